@@ -0,0 +1,196 @@
+use std::cmp::Ordering;
+
+use aoc_common::svg::Svg;
+
+use crate::window_changes;
+
+const DEPTH_COLOUR: &str = "#1f77b4";
+const INCREASE_COLOUR: &str = "#d62728";
+const WINDOW_COLOURS: [&str; 4] = ["#2ca02c", "#9467bd", "#ff7f0e", "#8c564b"];
+
+/// Settings for `render_svg`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartOptions {
+    pub width: u32,
+    pub height: u32,
+    /// A line is overlaid on the depths for each of these window sizes.
+    pub window_sizes: Vec<usize>,
+}
+
+impl Default for ChartOptions {
+    fn default() -> Self {
+        Self {
+            width: 800,
+            height: 300,
+            window_sizes: vec![3],
+        }
+    }
+}
+
+/// Maps readings onto the plot area of the chart. Depth increases down the
+/// chart, as it would when looking at a cross section of the sea floor.
+struct Plot {
+    min: f64,
+    max: f64,
+    num_readings: usize,
+    width: f64,
+    height: f64,
+}
+
+impl Plot {
+    fn point(&self, index: f64, depth: f64) -> (f64, f64) {
+        let x = match self.num_readings {
+            0 | 1 => self.width / 2.0,
+            n => index / (n - 1) as f64 * self.width,
+        };
+        let y = match self.max - self.min {
+            range if range > 0.0 => (depth - self.min) / range * self.height,
+            _ => self.height / 2.0,
+        };
+        (x, y)
+    }
+}
+
+/// Renders the depths as an SVG line chart.
+///
+/// Each window size in `options` is overlaid as a line through the windows'
+/// totals. Totals are drawn divided by the window size so that they share the
+/// depth axis, which doesn't change whether one window is deeper than the
+/// next. Every stretch of the sweep where the depth is increasing is drawn in
+/// a highlight colour.
+///
+/// Panics if any of the window sizes is zero.
+pub fn render_svg(depths: &[i32], options: &ChartOptions) -> String {
+    assert!(
+        options.window_sizes.iter().all(|&size| size > 0),
+        "window sizes must be at least 1"
+    );
+    let mut svg = Svg::new(options.width, options.height);
+    let plot = Plot {
+        min: depths.iter().copied().min().unwrap_or(0) as f64,
+        max: depths.iter().copied().max().unwrap_or(0) as f64,
+        num_readings: depths.len(),
+        width: svg.plot_width(),
+        height: svg.plot_height(),
+    };
+
+    let points = depths
+        .iter()
+        .enumerate()
+        .map(|(i, &depth)| plot.point(i as f64, depth as f64));
+    svg.polyline(points, DEPTH_COLOUR, 1.0);
+
+    // Highlight each run of readings that are deeper than the one before.
+    let mut run_start = None;
+    let changes = window_changes(depths, 1).map(Some).chain([None]);
+    for (i, change) in changes.enumerate() {
+        match (change, run_start) {
+            (Some(Ordering::Greater), None) => run_start = Some(i),
+            (Some(Ordering::Greater), Some(_)) => (),
+            (_, Some(start)) => {
+                let points = (start..=i).map(|j| plot.point(j as f64, depths[j] as f64));
+                svg.polyline(points, INCREASE_COLOUR, 2.0);
+                run_start = None;
+            }
+            (_, None) => (),
+        }
+    }
+
+    for (&window_size, colour) in options
+        .window_sizes
+        .iter()
+        .zip(WINDOW_COLOURS.iter().cycle())
+    {
+        let points = depths.windows(window_size).enumerate().map(|(i, window)| {
+            let mean = window.iter().map(|&depth| depth as f64).sum::<f64>() / window_size as f64;
+            // Centre each window over the readings it covers.
+            plot.point(i as f64 + (window_size - 1) as f64 / 2.0, mean)
+        });
+        svg.polyline(points, colour, 1.5);
+    }
+
+    let legend = [("depth", DEPTH_COLOUR), ("increasing", INCREASE_COLOUR)]
+        .into_iter()
+        .map(|(label, colour)| (label.to_owned(), colour))
+        .chain(
+            options
+                .window_sizes
+                .iter()
+                .zip(WINDOW_COLOURS.iter().cycle())
+                .map(|(size, &colour)| (format!("window of {}", size), colour)),
+        );
+    for (label, colour) in legend {
+        svg.legend(&label, colour);
+    }
+
+    svg.finish()
+}
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Renders the depths as a line of block characters, at most `width`
+/// characters long. Longer sweeps are split into `width` buckets and each
+/// bucket is drawn at its mean depth.
+pub fn sparkline(depths: &[i32], width: usize) -> String {
+    let num_buckets = depths.len().min(width);
+    let buckets: Vec<f64> = (0..num_buckets)
+        .map(|bucket| {
+            let start = bucket * depths.len() / num_buckets;
+            let end = (bucket + 1) * depths.len() / num_buckets;
+            let total: f64 = depths[start..end].iter().map(|&depth| depth as f64).sum();
+            total / (end - start) as f64
+        })
+        .collect();
+
+    let min = buckets.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = buckets.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let top = (SPARKS.len() - 1) as f64;
+
+    buckets
+        .iter()
+        .map(|&depth| match max - min {
+            range if range > 0.0 => SPARKS[((depth - min) / range * top).round() as usize],
+            _ => SPARKS[0],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chart::{render_svg, sparkline, ChartOptions},
+        parse, EXAMPLE,
+    };
+
+    #[test]
+    fn test_sparkline() {
+        let depths = parse(EXAMPLE).unwrap();
+        assert_eq!(sparkline(&depths, 80), "▁▁▂▂▁▂▅█▇▇");
+        assert_eq!(sparkline(&depths, 5), "▁▂▁▇█");
+        assert_eq!(sparkline(&[5, 5, 5], 80), "▁▁▁");
+        assert_eq!(sparkline(&[], 80), "");
+    }
+
+    #[test]
+    fn test_render_svg() {
+        let depths = parse(EXAMPLE).unwrap();
+        let options = ChartOptions {
+            window_sizes: vec![1, 3],
+            ..ChartOptions::default()
+        };
+        let svg = render_svg(&depths, &options);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\""));
+        assert!(svg.ends_with("</svg>\n"));
+
+        // The depths, three increasing runs and one line for each window.
+        assert_eq!(svg.matches("<polyline").count(), 6);
+        assert_eq!(svg.matches("stroke=\"#d62728\"").count(), 3);
+        assert!(svg.contains("window of 3"));
+
+        // The shallowest reading is at the top left.
+        assert!(svg.contains("points=\"40.0,40.0 "));
+
+        let svg = render_svg(&[], &ChartOptions::default());
+        assert_eq!(svg.matches("<polyline").count(), 0);
+    }
+}
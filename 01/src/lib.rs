@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+
+use aoc_common::{ParseError, Solver, Span};
+
+pub mod chart;
+pub mod report;
+pub mod window;
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    Span::new(input).lines()
+        .map(|x| x.parse("a depth"))
+        .collect()
+}
+
+/// Compares the total of each window with the total of the window before it,
+/// giving whether each one rose, fell or stayed the same.
+/// 
+/// Windows are contiguous, fixed size slices of the array that overlap starting
+/// at the beginning and increasing in their starting index by 1 until no more
+/// full windows can be created. Fewer than two full windows means there is
+/// nothing to compare, so nothing is returned.
+///
+/// See `window::WindowAnalyzer` for the same comparison over a stream.
+pub fn window_changes(numbers: &[i32], window_size: usize) -> impl Iterator<Item = Ordering> + '_ {
+    // Because the windows being compared are offset by 1 index all but the
+    // first and last numbers are the same and so can be cancelled out.
+    let later = numbers.get(window_size..).unwrap_or_default();
+    numbers.iter().zip(later).map(|(first, last)| last.cmp(first))
+}
+
+/// Counts the windows that increase in total value when compared to the
+/// previous window.
+pub fn count_increases_windowed(numbers: &[i32], window_size: usize) -> i32 {
+    window_changes(numbers, window_size)
+        .filter(|&change| change == Ordering::Greater)
+        .count() as i32
+}
+
+pub fn part1(numbers: &[i32]) -> i32 {
+    count_increases_windowed(numbers, 1)
+}
+
+pub fn part2(numbers: &[i32]) -> i32 {
+    count_increases_windowed(numbers, 3)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 1;
+    const NAME: &'static str = "Sonar Sweep";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::{count_increases_windowed, Puzzle};
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+
+    #[test]
+    fn test_fewer_depths_than_window() {
+        assert_eq!(count_increases_windowed(&[199, 200], 3), 0);
+        assert_eq!(count_increases_windowed(&[], 1), 0);
+    }
+}
@@ -0,0 +1,319 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::Range,
+};
+
+use crate::window_changes;
+
+/// Settings for building a `DepthReport`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReportOptions {
+    /// The size of the windows whose totals are compared to find runs.
+    pub window_size: usize,
+    /// How many of the preceding readings the rolling mean is taken over.
+    pub mean_window: usize,
+    /// How far a reading may be from the rolling mean, in either direction,
+    /// before it is reported as an anomaly.
+    pub threshold: f64,
+}
+
+impl Default for ReportOptions {
+    fn default() -> Self {
+        Self {
+            window_size: 1,
+            mean_window: 10,
+            threshold: 50.0,
+        }
+    }
+}
+
+/// A single depth along with its position in the sweep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reading {
+    pub index: usize,
+    pub depth: i32,
+}
+
+/// The change in depth between the reading at `index` and the one after it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Jump {
+    pub index: usize,
+    pub change: i64,
+}
+
+/// A reading that is further from the mean of the readings before it than
+/// the configured threshold.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Anomaly {
+    pub reading: Reading,
+    pub mean: f64,
+}
+
+/// A summary of a sonar sweep.
+///
+/// Runs are ranges of window indices, where window `i` starts at reading `i`,
+/// over which every window's total strictly increased or decreased compared
+/// with the one before. With the default window size of 1 they are simply
+/// ranges of readings.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DepthReport {
+    pub window_size: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub longest_increase: Option<Range<usize>>,
+    pub longest_decrease: Option<Range<usize>>,
+    pub min: Option<Reading>,
+    pub max: Option<Reading>,
+    pub largest_jump: Option<Jump>,
+    pub anomalies: Vec<Anomaly>,
+}
+
+impl DepthReport {
+    /// Panics if either window in `options` is empty.
+    pub fn new(depths: &[i32], options: ReportOptions) -> Self {
+        assert!(
+            options.window_size > 0 && options.mean_window > 0,
+            "report windows must be at least 1"
+        );
+
+        let mut report = Self {
+            window_size: options.window_size,
+            ..Self::default()
+        };
+        report.add_runs(depths);
+        report.add_extremes(depths);
+        report.add_anomalies(depths, options);
+        report
+    }
+
+    fn add_runs(&mut self, depths: &[i32]) {
+        let mut increase = RunTracker::default();
+        let mut decrease = RunTracker::default();
+
+        for (i, change) in window_changes(depths, self.window_size).enumerate() {
+            // Comparison `i` is between windows `i` and `i + 1`.
+            increase.update(i, change == Ordering::Greater);
+            decrease.update(i, change == Ordering::Less);
+        }
+
+        self.increases = increase.total;
+        self.decreases = decrease.total;
+        self.longest_increase = increase.longest;
+        self.longest_decrease = decrease.longest;
+    }
+
+    fn add_extremes(&mut self, depths: &[i32]) {
+        let readings = depths
+            .iter()
+            .enumerate()
+            .map(|(index, &depth)| Reading { index, depth });
+
+        // Ties go to the earliest reading.
+        self.min = readings.clone().min_by_key(|reading| reading.depth);
+        self.max = readings.rev().max_by_key(|reading| reading.depth);
+
+        self.largest_jump = depths
+            .windows(2)
+            .enumerate()
+            .map(|(index, pair)| Jump {
+                index,
+                change: pair[1] as i64 - pair[0] as i64,
+            })
+            .rev()
+            .max_by_key(|jump| jump.change.abs());
+    }
+
+    fn add_anomalies(&mut self, depths: &[i32], options: ReportOptions) {
+        let mut total: i64 = depths
+            .iter()
+            .take(options.mean_window)
+            .map(|&depth| depth as i64)
+            .sum();
+
+        for index in options.mean_window..depths.len() {
+            let mean = total as f64 / options.mean_window as f64;
+            let depth = depths[index];
+            if (depth as f64 - mean).abs() > options.threshold {
+                self.anomalies.push(Anomaly {
+                    reading: Reading { index, depth },
+                    mean,
+                });
+            }
+
+            total += depth as i64 - depths[index - options.mean_window] as i64;
+        }
+    }
+}
+
+/// Keeps track of the current and longest stretch of comparisons that all
+/// went the same way.
+#[derive(Default)]
+struct RunTracker {
+    total: usize,
+    start: Option<usize>,
+    longest: Option<Range<usize>>,
+}
+
+impl RunTracker {
+    fn update(&mut self, comparison: usize, matches: bool) {
+        if !matches {
+            self.start = None;
+            return;
+        }
+
+        self.total += 1;
+        let start = *self.start.get_or_insert(comparison);
+        // Comparisons `start..=comparison` cover windows `start..=comparison + 1`.
+        let run = start..comparison + 2;
+        if self
+            .longest
+            .as_ref()
+            .is_none_or(|longest| run.len() > longest.len())
+        {
+            self.longest = Some(run);
+        }
+    }
+}
+
+impl Display for DepthReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let run = |run: &Option<Range<usize>>| match run {
+            Some(run) => format!("{} windows ({}..{})", run.len(), run.start, run.end),
+            None => "none".to_owned(),
+        };
+        let reading = |reading: &Option<Reading>| match reading {
+            Some(reading) => format!("{} (reading {})", reading.depth, reading.index),
+            None => "none".to_owned(),
+        };
+
+        writeln!(f, "Window size: {}", self.window_size)?;
+        writeln!(f, "Increases: {}", self.increases)?;
+        writeln!(f, "Decreases: {}", self.decreases)?;
+        writeln!(f, "Longest increase: {}", run(&self.longest_increase))?;
+        writeln!(f, "Longest decrease: {}", run(&self.longest_decrease))?;
+        writeln!(f, "Min depth: {}", reading(&self.min))?;
+        writeln!(f, "Max depth: {}", reading(&self.max))?;
+        match self.largest_jump {
+            Some(jump) => writeln!(
+                f,
+                "Largest jump: {:+} (readings {} to {})",
+                jump.change,
+                jump.index,
+                jump.index + 1
+            )?,
+            None => writeln!(f, "Largest jump: none")?,
+        }
+        writeln!(f, "Anomalies: {}", self.anomalies.len())?;
+        for anomaly in &self.anomalies {
+            writeln!(
+                f,
+                "  reading {}: {} against a mean of {:.1}",
+                anomaly.reading.index, anomaly.reading.depth, anomaly.mean
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        count_increases_windowed, parse,
+        report::{DepthReport, Jump, Reading, ReportOptions},
+        EXAMPLE,
+    };
+
+    #[test]
+    fn test_report() {
+        let depths = parse(EXAMPLE).unwrap();
+        let report = DepthReport::new(&depths, ReportOptions::default());
+        assert_eq!(report.increases, 7);
+        assert_eq!(report.decreases, 2);
+        assert_eq!(report.longest_increase, Some(0..4));
+        assert_eq!(report.longest_decrease, Some(3..5));
+        assert_eq!(
+            report.min,
+            Some(Reading {
+                index: 0,
+                depth: 199
+            })
+        );
+        assert_eq!(
+            report.max,
+            Some(Reading {
+                index: 7,
+                depth: 269
+            })
+        );
+        assert_eq!(
+            report.largest_jump,
+            Some(Jump {
+                index: 5,
+                change: 33
+            })
+        );
+        assert!(report.anomalies.is_empty());
+    }
+
+    #[test]
+    fn test_report_windows() {
+        let depths = parse(EXAMPLE).unwrap();
+        for window_size in 1..=4 {
+            let options = ReportOptions {
+                window_size,
+                ..ReportOptions::default()
+            };
+            let report = DepthReport::new(&depths, options);
+            let increases = count_increases_windowed(&depths, window_size);
+            assert_eq!(report.increases, increases as usize);
+        }
+
+        let options = ReportOptions {
+            window_size: 3,
+            ..ReportOptions::default()
+        };
+        let report = DepthReport::new(&depths, options);
+        // Window totals are 607, 618, 618, 617, 647, 716, 769, 792.
+        assert_eq!(report.longest_increase, Some(3..8));
+        assert_eq!(report.longest_decrease, Some(2..4));
+
+        let report = DepthReport::new(&depths[..2], options);
+        assert_eq!(report.longest_increase, None);
+        assert_eq!(
+            report.min,
+            Some(Reading {
+                index: 0,
+                depth: 199
+            })
+        );
+    }
+
+    #[test]
+    fn test_anomalies() {
+        let depths = [100, 102, 101, 103, 160, 104, 105, 40];
+        let options = ReportOptions {
+            window_size: 1,
+            mean_window: 3,
+            threshold: 30.0,
+        };
+        let report = DepthReport::new(&depths, options);
+        let flagged: Vec<_> = report
+            .anomalies
+            .iter()
+            .map(|anomaly| anomaly.reading.index)
+            .collect();
+        // The spike at 4 lifts the mean for the next few readings, but not by
+        // enough to flag them.
+        assert_eq!(flagged, vec![4, 7]);
+        assert_eq!(report.anomalies[0].mean, 102.0);
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = DepthReport::new(&[], ReportOptions::default());
+        assert_eq!(report.min, None);
+        assert_eq!(report.largest_jump, None);
+        assert!(report.to_string().contains("Longest increase: none"));
+    }
+}
@@ -0,0 +1,262 @@
+use std::{
+    cmp::Ordering,
+    collections::VecDeque,
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead},
+    iter,
+};
+
+use aoc_common::{ParseError, Span};
+
+/// How the sum of a sliding window changed each time it moved along by one
+/// reading.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindowCounts {
+    pub window_size: usize,
+    pub increases: u64,
+    pub decreases: u64,
+    pub plateaus: u64,
+}
+
+/// Compares sliding windows of several sizes over a stream of depths in a
+/// single pass.
+///
+/// Consecutive windows of the same size share all but their first and last
+/// readings, so comparing their sums only needs the reading that is about to
+/// leave the window. Only the most recent readings for the largest window are
+/// kept, which means memory use doesn't grow with the length of the stream.
+#[derive(Clone, Debug)]
+pub struct WindowAnalyzer {
+    counts: Vec<WindowCounts>,
+    recent: VecDeque<i32>,
+    capacity: usize,
+}
+
+impl WindowAnalyzer {
+    /// Panics if any of the window sizes is zero.
+    pub fn new(window_sizes: &[usize]) -> Self {
+        assert!(
+            window_sizes.iter().all(|&size| size > 0),
+            "window sizes must be at least 1"
+        );
+
+        let counts = window_sizes
+            .iter()
+            .map(|&window_size| WindowCounts {
+                window_size,
+                ..WindowCounts::default()
+            })
+            .collect();
+        let capacity = window_sizes.iter().copied().max().unwrap_or(0);
+
+        Self {
+            counts,
+            recent: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn push(&mut self, depth: i32) {
+        let num_recent = self.recent.len();
+        for counts in &mut self.counts {
+            // The reading that drops out of a window of this size as `depth`
+            // joins it, if there have been enough readings to fill one.
+            let leaving = num_recent
+                .checked_sub(counts.window_size)
+                .map(|index| self.recent[index]);
+
+            match leaving.map(|leaving| depth.cmp(&leaving)) {
+                Some(Ordering::Greater) => counts.increases += 1,
+                Some(Ordering::Less) => counts.decreases += 1,
+                Some(Ordering::Equal) => counts.plateaus += 1,
+                None => (),
+            }
+        }
+
+        if self.capacity > 0 {
+            if num_recent == self.capacity {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(depth);
+        }
+    }
+
+    /// The counts so far, in the order the window sizes were given.
+    pub fn counts(&self) -> &[WindowCounts] {
+        &self.counts
+    }
+
+    pub fn into_counts(self) -> Vec<WindowCounts> {
+        self.counts
+    }
+}
+
+impl Extend<i32> for WindowAnalyzer {
+    fn extend<I: IntoIterator<Item = i32>>(&mut self, depths: I) {
+        depths.into_iter().for_each(|depth| self.push(depth));
+    }
+}
+
+/// Analyzes every window size over `depths` in a single pass.
+pub fn analyze(window_sizes: &[usize], depths: impl IntoIterator<Item = i32>) -> Vec<WindowCounts> {
+    let mut analyzer = WindowAnalyzer::new(window_sizes);
+    analyzer.extend(depths);
+    analyzer.into_counts()
+}
+
+/// Analyzes every window size over the depths in `reader`, one per line,
+/// without reading it all into memory first.
+pub fn analyze_reader(
+    window_sizes: &[usize],
+    reader: impl BufRead,
+) -> Result<Vec<WindowCounts>, ReadError> {
+    let mut analyzer = WindowAnalyzer::new(window_sizes);
+    for depth in read_depths(reader) {
+        analyzer.push(depth?);
+    }
+    Ok(analyzer.into_counts())
+}
+
+/// Why depths could not be read from a reader.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read depths: {}", err),
+            Self::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// Lazily reads one depth per line, with the same handling of line endings
+/// and trailing blank lines as `parse`. Reading stops after the first error.
+pub fn read_depths(reader: impl BufRead) -> impl Iterator<Item = Result<i32, ReadError>> {
+    let mut lines = reader.lines();
+    let mut line_number = 0;
+    // Blank lines are only an error if another depth follows them.
+    let mut first_blank = None;
+    let mut failed = false;
+
+    iter::from_fn(move || {
+        while !failed {
+            let line = match lines.next()? {
+                Ok(line) => line,
+                Err(err) => {
+                    failed = true;
+                    return Some(Err(err.into()));
+                }
+            };
+            line_number += 1;
+
+            let text = line.trim_end();
+            if text.is_empty() {
+                first_blank = first_blank.or(Some(line_number));
+                continue;
+            }
+
+            let result = match first_blank {
+                Some(blank) => Err(ParseError {
+                    line: blank,
+                    ..Span::new("").error("a depth")
+                }),
+                None => Span::new(text).parse("a depth").map_err(|err| ParseError {
+                    line: line_number,
+                    ..err
+                }),
+            };
+            failed = result.is_err();
+            return Some(result.map_err(ReadError::from));
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        count_increases_windowed, parse,
+        window::{analyze, analyze_reader, read_depths, ReadError, WindowCounts},
+        EXAMPLE,
+    };
+
+    #[test]
+    fn test_analyze_window_sizes() {
+        let depths = parse(EXAMPLE).unwrap();
+        let counts = analyze(&[1, 3, 20], depths.iter().copied());
+        assert_eq!(
+            counts,
+            vec![
+                WindowCounts {
+                    window_size: 1,
+                    increases: 7,
+                    decreases: 2,
+                    plateaus: 0,
+                },
+                WindowCounts {
+                    window_size: 3,
+                    increases: 5,
+                    decreases: 1,
+                    plateaus: 1,
+                },
+                WindowCounts {
+                    window_size: 20,
+                    ..WindowCounts::default()
+                },
+            ]
+        );
+
+        for window_size in 1..=12 {
+            let counts = analyze(&[window_size], depths.iter().copied());
+            let expected = count_increases_windowed(&depths, window_size);
+            assert_eq!(counts[0].increases, expected as u64);
+        }
+    }
+
+    #[test]
+    fn test_analyze_reader() {
+        let counts = analyze_reader(&[1, 3], EXAMPLE.as_bytes()).unwrap();
+        assert_eq!((counts[0].increases, counts[1].increases), (7, 5));
+
+        let input = "199\r\n200\r\n208 \r\n\r\n\r\n";
+        let depths: Vec<_> = read_depths(input.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(depths, vec![199, 200, 208]);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let depths: Vec<_> = read_depths("199\n2x0\n208\n".as_bytes()).collect();
+        assert_eq!(depths.len(), 2);
+        let Err(ReadError::Parse(err)) = &depths[1] else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected a depth, found `2x0`"
+        );
+
+        let Err(ReadError::Parse(err)) = analyze_reader(&[1], "199\n\n\n200\n".as_bytes()) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
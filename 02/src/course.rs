@@ -0,0 +1,282 @@
+//! The language planned courses are written in.
+//!
+//! Each command is a direction followed by a distance on the same line, as in
+//! the puzzle input:
+//!
+//! ```text
+//! forward 5   # anything after a `#` is a comment
+//! back 2
+//!
+//! repeat 3 {
+//!     down 1
+//!     forward 2
+//! }
+//!
+//! macro dive { down 4 forward 1 }
+//! dive
+//! ```
+//!
+//! `repeat N { ... }` runs its body `N` times and `macro NAME { ... }` defines
+//! a name that can be used in place of its body anywhere after it. Commands,
+//! blocks and macro uses may share a line, but the count or name that follows
+//! a keyword must be on the same line as it. Everything is expanded while
+//! parsing, so the result is the flat list of commands the submarine follows.
+
+use std::{collections::HashMap, iter::Peekable, vec};
+
+use aoc_common::{ParseError, Span};
+
+use crate::{Command, Direction};
+
+/// The most commands a course can expand to, so that a few nested `repeat`
+/// blocks can't exhaust memory. The bodies of macros count towards it too,
+/// since they are kept for the rest of the course.
+pub const MAX_COMMANDS: usize = 10_000_000;
+
+const KEYWORDS: [&str; 6] = ["forward", "back", "up", "down", "repeat", "macro"];
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    let input = Span::new(input);
+    let mut parser = Parser {
+        tokens: tokenize(input).into_iter().peekable(),
+        macros: HashMap::new(),
+        end: input.trim_end().end(),
+        num_stored: 0,
+    };
+    parser.block(None)
+}
+
+/// Splits the input into words and braces, leaving out comments.
+fn tokenize(input: Span) -> Vec<Span> {
+    let mut tokens = Vec::new();
+    for line in input.lines() {
+        let code = line.split("#").next().unwrap_or(line);
+        for mut word in code.split_whitespace() {
+            while let Some(brace) = word.as_str().find(['{', '}']) {
+                let (before, rest) = word.split_at(brace);
+                let (brace, after) = rest.split_at(1);
+                tokens.extend([before, brace].into_iter().filter(|span| !span.is_empty()));
+                word = after;
+            }
+            if !word.is_empty() {
+                tokens.push(word);
+            }
+        }
+    }
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: Peekable<vec::IntoIter<Span<'a>>>,
+    macros: HashMap<&'a str, Vec<Command>>,
+    end: Span<'a>,
+    /// How many commands are held in blocks and macros so far.
+    num_stored: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Parses commands up to the `}` matching `open`, or to the end of the
+    /// input at the top level.
+    fn block(&mut self, open: Option<Span>) -> Result<Vec<Command>, ParseError> {
+        let mut commands = Vec::new();
+        loop {
+            let Some(token) = self.tokens.next() else {
+                return match open {
+                    None => Ok(commands),
+                    Some(open) => Err(ParseError {
+                        found: "end of input".to_owned(),
+                        ..self.end.error(format!(
+                            "`}}` to close the block opened on line {}",
+                            open.line()
+                        ))
+                    }),
+                };
+            };
+
+            match token.as_str() {
+                "}" if open.is_some() => return Ok(commands),
+                "repeat" => {
+                    let count: usize = self.next_on_line(token, "a repeat count")?;
+                    let body = self.body(token)?;
+                    // The body is only needed until it has been copied out.
+                    self.num_stored -= body.len();
+                    extend(&mut commands, &body, count, token, &mut self.num_stored)?;
+                }
+                "macro" => {
+                    let name = self.word_on_line(token, "a macro name")?;
+                    let is_valid =
+                        |char: char| char.is_alphanumeric() || char == '_' || char == '-';
+                    if KEYWORDS.contains(&name.as_str()) || !name.as_str().chars().all(is_valid) {
+                        return Err(name.error("a macro name"));
+                    }
+                    let body = self.body(name)?;
+                    if let Some(replaced) = self.macros.insert(name.as_str(), body) {
+                        self.num_stored -= replaced.len();
+                    }
+                }
+                word => {
+                    if let Some(direction) = Direction::from_name(word) {
+                        let command = Command {
+                            direction,
+                            distance: self.next_on_line(token, "a distance")?,
+                        };
+                        extend(&mut commands, &[command], 1, token, &mut self.num_stored)?;
+                    } else if let Some(body) = self.macros.get(word) {
+                        extend(&mut commands, body, 1, token, &mut self.num_stored)?;
+                    } else {
+                        return Err(token.error("a command"));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parses a `{ ... }` block, which must start on the same line as
+    /// `header`.
+    fn body(&mut self, header: Span) -> Result<Vec<Command>, ParseError> {
+        let open = self.word_on_line(header, "`{`")?;
+        if open.as_str() != "{" {
+            return Err(open.error("`{`"));
+        }
+        self.block(Some(open))
+    }
+
+    /// Takes the next token, as long as it is on the same line as `previous`.
+    fn word_on_line(&mut self, previous: Span, expected: &str) -> Result<Span<'a>, ParseError> {
+        self.tokens
+            .next_if(|token| token.line() == previous.line())
+            .ok_or_else(|| previous.end().error(expected))
+    }
+
+    fn next_on_line<T: std::str::FromStr>(
+        &mut self,
+        previous: Span,
+        expected: &str,
+    ) -> Result<T, ParseError> {
+        self.word_on_line(previous, expected)?.parse(expected)
+    }
+}
+
+/// Appends `times` copies of `body`, failing at `token` if that would take the
+/// number of commands stored past `MAX_COMMANDS`.
+fn extend(
+    commands: &mut Vec<Command>,
+    body: &[Command],
+    times: usize,
+    token: Span,
+    num_stored: &mut usize,
+) -> Result<(), ParseError> {
+    let added = body.len().saturating_mul(times);
+    if added > MAX_COMMANDS.saturating_sub(*num_stored) {
+        return Err(token.error(format!("a course of at most {} commands", MAX_COMMANDS)));
+    }
+    *num_stored += added;
+    for _ in 0..times {
+        commands.extend_from_slice(body);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{course::parse, Command, Direction, EXAMPLE, INPUT};
+
+    fn command(direction: Direction, distance: i32) -> Command {
+        Command {
+            direction,
+            distance,
+        }
+    }
+
+    #[test]
+    fn test_plain_courses() {
+        let commands = parse(EXAMPLE).unwrap();
+        assert_eq!(commands.len(), 6);
+        assert_eq!(commands[0], command(Direction::Forward, 5));
+        assert_eq!(parse(INPUT).unwrap().len(), INPUT.lines().count());
+        assert_eq!(parse("forward 5\r\nup 3\r\n\r\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_extended_courses() {
+        let input = "\
+# Head out, then come back a little.
+forward 5 # full speed
+back 2
+
+repeat 2 {
+    down 1
+    repeat 2 { forward 1 }
+}
+
+macro surface { up 2 }
+surface
+";
+        let commands = parse(input).unwrap();
+        assert_eq!(
+            commands,
+            vec![
+                command(Direction::Forward, 5),
+                command(Direction::Back, 2),
+                command(Direction::Down, 1),
+                command(Direction::Forward, 1),
+                command(Direction::Forward, 1),
+                command(Direction::Down, 1),
+                command(Direction::Forward, 1),
+                command(Direction::Forward, 1),
+                command(Direction::Up, 2),
+            ]
+        );
+        assert_eq!(parse("repeat 0 {}\n# nothing\n").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |input| parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("forward 5\nsideways 3\n"),
+            "line 2, column 1: expected a command, found `sideways`"
+        );
+        assert_eq!(
+            error("forward\n5\n"),
+            "line 1, column 8: expected a distance, found end of line"
+        );
+        assert_eq!(
+            error("repeat 2 {\n    down 1\n"),
+            "line 2, column 11: expected `}` to close the block opened on line 1, found end of input"
+        );
+        assert_eq!(
+            error("down 1\n}\n"),
+            "line 2, column 1: expected a command, found `}`"
+        );
+        assert_eq!(
+            error("macro up { down 1 }\n"),
+            "line 1, column 7: expected a macro name, found `up`"
+        );
+        assert_eq!(
+            error("dive\nmacro dive { down 1 }\n"),
+            "line 1, column 1: expected a command, found `dive`"
+        );
+        assert_eq!(
+            error("repeat 100000 { repeat 100000 { up 1 } }\n"),
+            "line 1, column 1: expected a course of at most 10000000 commands, found `repeat`"
+        );
+        // Single commands count towards the limit too.
+        assert_eq!(
+            error("repeat 10000000 { up 1 }\ndown 1\n"),
+            "line 2, column 1: expected a course of at most 10000000 commands, found `down`"
+        );
+        // As do the commands kept for macros, even if they're never used.
+        assert_eq!(
+            error("macro a { repeat 6000000 { up 1 } }\nmacro b { repeat 6000000 { up 1 } }\n"),
+            "line 2, column 11: expected a course of at most 10000000 commands, found `repeat`"
+        );
+        // Redefining a macro frees its old body.
+        let input = "\
+macro a { repeat 6000000 { up 1 } }
+macro a { up 1 }
+macro b { repeat 6000000 { up 1 } }
+";
+        assert_eq!(parse(input), Ok(vec![]));
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::{self, Display};
+
+use aoc_common::{ParseError, Solver};
+
+use crate::model::{simulate, AimModel, DirectModel, Overflow};
+
+pub mod course;
+pub mod model;
+pub mod plan;
+pub mod trajectory;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Back,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "forward" => Some(Self::Forward),
+            "back" => Some(Self::Back),
+            "up" => Some(Self::Up),
+            "down" => Some(Self::Down),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Forward => write!(f, "forward"),
+            Self::Back => write!(f, "back"),
+            Self::Up => write!(f, "up"),
+            Self::Down => write!(f, "down"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Command {
+    pub direction: Direction,
+    pub distance: i32,
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.direction, self.distance)
+    }
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+/// Parses a course, which may use any of the extensions described in
+/// `course`.
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    course::parse(input)
+}
+
+pub fn part1(commands: &[Command]) -> Result<i64, Overflow> {
+    simulate(&DirectModel, commands)?.product()
+}
+
+pub fn part2(commands: &[Command]) -> Result<i64, Overflow> {
+    simulate(&AimModel, commands)?.product()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 2;
+    const NAME: &'static str = "Dive!";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Vec<Command>;
+    type Part1 = Result<i64, Overflow>;
+    type Part2 = Result<i64, Overflow>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{
+        expected::assert_example,
+        runner::{solve, SolveError},
+        Part,
+    };
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+
+    #[test]
+    fn test_overflow_is_reported() {
+        let input = "repeat 5 { down 2000000000 }\nforward 2000000000\n";
+        let error = solve::<Puzzle>(input, &[Part::One]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the product of position 2000000000 and depth 10000000000 is out of range"
+        );
+
+        let error = solve::<Puzzle>(input, &[Part::Two]).unwrap_err();
+        assert_eq!(
+            error,
+            SolveError::Part {
+                part: Part::Two,
+                message: "command 6 (`forward 2000000000`) takes the submarine out of range"
+                    .to_owned(),
+            }
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{plan, Command, Direction};
+
+/// Where the submarine is and which way it is pointing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Submarine {
+    pub position: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Submarine {
+    /// The horizontal position multiplied by the depth, which is the answer
+    /// the puzzle asks for.
+    pub fn product(&self) -> Result<i64, Overflow> {
+        self.position
+            .checked_mul(self.depth)
+            .ok_or(Overflow::Product(*self))
+    }
+}
+
+/// A value that no longer fits in an `i64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// Carrying out the command at index `step` of the course took the
+    /// submarine out of range.
+    Command { step: usize, command: Command },
+    /// The submarine's position multiplied by its depth is out of range.
+    Product(Submarine),
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Command { step, command } => write!(
+                f,
+                "command {} (`{}`) takes the submarine out of range",
+                step + 1,
+                command
+            ),
+            Self::Product(submarine) => write!(
+                f,
+                "the product of position {} and depth {} is out of range",
+                submarine.position, submarine.depth
+            ),
+        }
+    }
+}
+
+impl Error for Overflow {}
+
+/// A way of interpreting the commands in a planned course.
+pub trait SubmarineModel {
+    /// The name the model is registered under.
+    fn name(&self) -> &str;
+
+    /// Returns the state of the submarine after carrying out `command`, or
+    /// `None` if any part of it would overflow.
+    fn apply(&self, submarine: Submarine, command: &Command) -> Option<Submarine>;
+
+    /// A course that leaves the submarine at `position` and `depth`, if the
+    /// model knows how to find one.
+    fn plan(&self, _position: i64, _depth: i64) -> Option<Vec<Command>> {
+        None
+    }
+}
+
+/// The first reading of the manual, where `up` and `down` change the depth
+/// directly and `back` reverses along the surface.
+pub struct DirectModel;
+
+impl SubmarineModel for DirectModel {
+    fn name(&self) -> &str {
+        "direct"
+    }
+
+    fn apply(&self, mut submarine: Submarine, command: &Command) -> Option<Submarine> {
+        let distance = command.distance as i64;
+        match command.direction {
+            Direction::Forward => submarine.position = submarine.position.checked_add(distance)?,
+            Direction::Back => submarine.position = submarine.position.checked_sub(distance)?,
+            Direction::Up => submarine.depth = submarine.depth.checked_sub(distance)?,
+            Direction::Down => submarine.depth = submarine.depth.checked_add(distance)?,
+        }
+        Some(submarine)
+    }
+
+    fn plan(&self, position: i64, depth: i64) -> Option<Vec<Command>> {
+        plan::direct(position, depth)
+    }
+}
+
+/// The corrected reading of the manual, where `up` and `down` change the aim
+/// and moving forward also changes the depth by the aim. Going `back` retraces
+/// the same slope in reverse.
+pub struct AimModel;
+
+impl SubmarineModel for AimModel {
+    fn name(&self) -> &str {
+        "aim"
+    }
+
+    fn apply(&self, mut submarine: Submarine, command: &Command) -> Option<Submarine> {
+        let distance = command.distance as i64;
+        match command.direction {
+            Direction::Forward => {
+                submarine.position = submarine.position.checked_add(distance)?;
+                let change = distance.checked_mul(submarine.aim)?;
+                submarine.depth = submarine.depth.checked_add(change)?;
+            }
+            Direction::Back => {
+                submarine.position = submarine.position.checked_sub(distance)?;
+                let change = distance.checked_mul(submarine.aim)?;
+                submarine.depth = submarine.depth.checked_sub(change)?;
+            }
+            Direction::Up => submarine.aim = submarine.aim.checked_sub(distance)?,
+            Direction::Down => submarine.aim = submarine.aim.checked_add(distance)?,
+        }
+        Some(submarine)
+    }
+
+    fn plan(&self, position: i64, depth: i64) -> Option<Vec<Command>> {
+        plan::aim(position, depth)
+    }
+}
+
+/// Runs every command through `model`, starting from the surface.
+pub fn simulate(model: &dyn SubmarineModel, commands: &[Command]) -> Result<Submarine, Overflow> {
+    commands
+        .iter()
+        .enumerate()
+        .try_fold(Submarine::default(), |submarine, (step, command)| {
+            model.apply(submarine, command).ok_or(Overflow::Command {
+                step,
+                command: *command,
+            })
+        })
+}
+
+/// The models that can be chosen by name.
+pub struct ModelRegistry {
+    models: Vec<Box<dyn SubmarineModel>>,
+}
+
+impl ModelRegistry {
+    /// A registry without any models in it.
+    pub fn empty() -> Self {
+        Self { models: Vec::new() }
+    }
+
+    /// Adds a model, replacing any existing model with the same name.
+    pub fn register(&mut self, model: impl SubmarineModel + 'static) {
+        self.models
+            .retain(|existing| existing.name() != model.name());
+        self.models.push(Box::new(model));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn SubmarineModel> {
+        self.models
+            .iter()
+            .find(|model| model.name() == name)
+            .map(|model| model.as_ref())
+    }
+
+    /// The names of the registered models, in the order they were added.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.models.iter().map(|model| model.name())
+    }
+
+    /// Runs the commands through the model called `name`, if there is one.
+    pub fn simulate(
+        &self,
+        name: &str,
+        commands: &[Command],
+    ) -> Option<Result<Submarine, Overflow>> {
+        self.get(name).map(|model| simulate(model, commands))
+    }
+
+    /// Plans a course to `position` and `depth` with the model called `name`.
+    pub fn plan(&self, name: &str, position: i64, depth: i64) -> Option<Vec<Command>> {
+        self.get(name)?.plan(position, depth)
+    }
+}
+
+/// A registry holding the two models from the puzzle.
+impl Default for ModelRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register(DirectModel);
+        registry.register(AimModel);
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{
+            simulate, AimModel, DirectModel, ModelRegistry, Overflow, Submarine, SubmarineModel,
+        },
+        parse, Command, Direction, EXAMPLE,
+    };
+
+    /// The aim model, but with the aim held within a range as if the
+    /// submarine could only pitch so far.
+    struct PitchLimited {
+        max_aim: i64,
+    }
+
+    impl SubmarineModel for PitchLimited {
+        fn name(&self) -> &str {
+            "pitch-limited"
+        }
+
+        fn apply(&self, submarine: Submarine, command: &Command) -> Option<Submarine> {
+            let mut submarine = AimModel.apply(submarine, command)?;
+            submarine.aim = submarine.aim.clamp(-self.max_aim, self.max_aim);
+            Some(submarine)
+        }
+    }
+
+    #[test]
+    fn test_builtin_models() {
+        let commands = parse(EXAMPLE).unwrap();
+        let registry = ModelRegistry::default();
+        assert_eq!(registry.names().collect::<Vec<_>>(), vec!["direct", "aim"]);
+
+        let direct = registry.simulate("direct", &commands).unwrap().unwrap();
+        assert_eq!((direct.position, direct.depth, direct.aim), (15, 10, 0));
+
+        let aim = simulate(&AimModel, &commands).unwrap();
+        assert_eq!((aim.position, aim.depth, aim.aim), (15, 60, 10));
+        assert_eq!(registry.simulate("missing", &commands), None);
+
+        // Going back retraces the slope, so the submarine ends up where it
+        // started but still pointing down.
+        let commands = parse("down 2\nforward 3\nback 3\n").unwrap();
+        let aim = simulate(&AimModel, &commands).unwrap();
+        assert_eq!((aim.position, aim.depth, aim.aim), (0, 0, 2));
+        let direct = registry.simulate("direct", &commands).unwrap().unwrap();
+        assert_eq!((direct.position, direct.depth), (0, 2));
+    }
+
+    #[test]
+    fn test_register_model() {
+        let commands = parse(EXAMPLE).unwrap();
+        let mut registry = ModelRegistry::default();
+        registry.register(PitchLimited { max_aim: 5 });
+
+        // The aim is held at 5 rather than reaching 10, so the final
+        // `forward 2` only descends by 10.
+        let limited = registry
+            .simulate("pitch-limited", &commands)
+            .unwrap()
+            .unwrap();
+        assert_eq!((limited.position, limited.depth, limited.aim), (15, 50, 5));
+
+        registry.register(PitchLimited { max_aim: 100 });
+        assert_eq!(registry.names().count(), 3);
+        let unlimited = registry
+            .simulate("pitch-limited", &commands)
+            .unwrap()
+            .unwrap();
+        assert_eq!(unlimited.product(), Ok(900));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // Each of these goes past `i32::MAX`, but fits comfortably in an
+        // `i64`.
+        let commands = parse("forward 2000000000\nforward 2000000000\ndown 3\n").unwrap();
+        let direct = simulate(&DirectModel, &commands).unwrap();
+        assert_eq!(direct.product(), Ok(12_000_000_000));
+        let aim = simulate(
+            &AimModel,
+            &parse("down 2000000000\nforward 2000000000\n").unwrap(),
+        );
+        assert_eq!(aim.unwrap().depth, 4_000_000_000_000_000_000);
+
+        let commands = parse("repeat 5 { down 2000000000 }\nforward 2000000000\n").unwrap();
+        let err = simulate(&AimModel, &commands).unwrap_err();
+        assert_eq!(
+            err,
+            Overflow::Command {
+                step: 5,
+                command: Command {
+                    direction: Direction::Forward,
+                    distance: 2_000_000_000,
+                },
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "command 6 (`forward 2000000000`) takes the submarine out of range"
+        );
+
+        // The state itself fits, but its product doesn't.
+        let far = Submarine {
+            position: 10_000_000_000,
+            depth: 10_000_000_000,
+            aim: 0,
+        };
+        assert_eq!(far.product(), Err(Overflow::Product(far)));
+    }
+}
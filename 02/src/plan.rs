@@ -0,0 +1,323 @@
+//! Works backwards from where the submarine should end up to a course that
+//! takes it there, which is the shortest one for all but the furthest
+//! targets.
+//!
+//! Plans only ever use positive distances, choosing `back` or `up` where a
+//! negative change is needed. A change that is too large for one command is
+//! split across as few commands as possible, so every target can be reached.
+//! `None` is only returned when the course would be longer than
+//! [`MAX_COMMANDS`], the most the course language allows.
+
+use crate::{course::MAX_COMMANDS, Command, Direction};
+
+/// The furthest a single command can move the submarine or change its aim.
+const MAX_DISTANCE: i64 = i32::MAX as i64;
+
+/// The shortest course to `position` and `depth` under `DirectModel`, which
+/// needs as many commands for each of them as it takes to cover the distance.
+///
+/// [`DirectModel`]: crate::model::DirectModel
+pub fn direct(position: i64, depth: i64) -> Option<Vec<Command>> {
+    let mut commands = travel(position)?;
+    commands.extend(dive(depth)?);
+    limit(commands)
+}
+
+/// A course to `position` and `depth` under `AimModel`.
+///
+/// Depth only changes while moving with a non-zero aim, so a target below the
+/// surface needs the aim set before moving. If the depth is a multiple of the
+/// position, the aim can be chosen so that moving the whole way covers both.
+/// Otherwise the submarine travels level and then sets the aim for one last
+/// move, choosing a length for that move that divides the depth. Travelling
+/// back first makes up for a last move that goes past the position. Between
+/// them, these find the shortest course to any target that can be reached in
+/// three commands.
+///
+/// A target that can't be reached in three commands is reached by travelling
+/// with the aim as steep as it can be set, which covers any depth but isn't
+/// always the shortest course there.
+///
+/// [`AimModel`]: crate::model::AimModel
+pub fn aim(position: i64, depth: i64) -> Option<Vec<Command>> {
+    if depth == 0 {
+        return direct(position, 0);
+    }
+    let short = [
+        aim_whole_way(position, depth),
+        aim_last_move(position, depth),
+    ]
+    .into_iter()
+    .flatten()
+    .min_by_key(Vec::len);
+    match short {
+        Some(commands) if commands.len() <= 3 => Some(commands),
+        short => short
+            .into_iter()
+            .chain(aim_steeply(position, depth))
+            .min_by_key(Vec::len)
+            .and_then(limit),
+    }
+}
+
+/// Sets the aim once and travels the whole of `position` with it.
+fn aim_whole_way(position: i64, depth: i64) -> Option<Vec<Command>> {
+    if position == 0 || depth.checked_rem(position) != Some(0) {
+        return None;
+    }
+    Some([dive(depth / position)?, travel(position)?].concat())
+}
+
+/// Travels level, then covers the whole depth with one aimed move.
+fn aim_last_move(position: i64, depth: i64) -> Option<Vec<Command>> {
+    let last = last_move(position, depth)?;
+    Some([travel(position - last)?, dive(depth / last)?, travel(last)?].concat())
+}
+
+/// Finds the length of a last move that `depth` is a multiple of, such that
+/// the move, the aim it needs and the level travel before it each fit in a
+/// single command. The move heads the same way as the target.
+fn last_move(position: i64, depth: i64) -> Option<i64> {
+    let heading = if position < 0 { -1 } else { 1 };
+    let depth = depth.unsigned_abs();
+    let max = MAX_DISTANCE as u64;
+    let fits =
+        |length: u64| length <= max && (position - heading * length as i64).unsigned_abs() <= max;
+
+    // Lengths pair up with the aim they need, and both have to fit, so it's
+    // enough to look for the smaller of each pair between the depth over the
+    // largest distance and the square root of the depth.
+    (depth.div_ceil(max).max(1)..=depth.isqrt())
+        .filter(|&small| depth.is_multiple_of(small))
+        .flat_map(|small| [small, depth / small])
+        .find(|&length| fits(length))
+        .map(|length| heading * length as i64)
+}
+
+/// Covers as much of the depth as it can with the aim as steep as it goes,
+/// then makes one more aimed move for whatever is left.
+fn aim_steeply(position: i64, depth: i64) -> Option<Vec<Command>> {
+    // The aimed moves head the same way as the target, so working out how far
+    // to travel level beforehand can't overflow.
+    let heading = if position < 0 { -1 } else { 1 };
+    let (steep, rest) = (depth / MAX_DISTANCE, depth % MAX_DISTANCE);
+    let steep_aim = heading * depth.signum() * MAX_DISTANCE;
+    let aimed = steep.abs() + i64::from(rest != 0);
+
+    let mut commands = travel(position - heading * aimed)?;
+    if steep != 0 {
+        commands.extend(dive(steep_aim)?);
+        commands.extend(travel(heading * steep.abs())?);
+    }
+    if rest != 0 {
+        let aim = if steep != 0 { steep_aim } else { 0 };
+        commands.extend(dive(heading * rest - aim)?);
+        commands.extend(travel(heading)?);
+    }
+    Some(commands)
+}
+
+/// Moves `amount` horizontally, backwards if it is negative.
+fn travel(amount: i64) -> Option<Vec<Command>> {
+    split(Direction::Forward, Direction::Back, amount)
+}
+
+/// Moves `amount` down, or up if it is negative.
+fn dive(amount: i64) -> Option<Vec<Command>> {
+    split(Direction::Down, Direction::Up, amount)
+}
+
+/// Covers `amount` with as few commands as possible, each going as far as it
+/// can. There are no commands for an amount of zero.
+fn split(positive: Direction, negative: Direction, amount: i64) -> Option<Vec<Command>> {
+    let direction = match amount {
+        0.. => positive,
+        _ => negative,
+    };
+    let distance = amount.unsigned_abs();
+    let num_commands = distance.div_ceil(MAX_DISTANCE as u64);
+    if num_commands > MAX_COMMANDS as u64 {
+        return None;
+    }
+
+    let full = Command {
+        direction,
+        distance: i32::MAX,
+    };
+    let mut commands = vec![full; (distance / MAX_DISTANCE as u64) as usize];
+    let rest = (distance % MAX_DISTANCE as u64) as i32;
+    if rest > 0 {
+        commands.push(Command {
+            direction,
+            distance: rest,
+        });
+    }
+    Some(commands)
+}
+
+fn limit(commands: Vec<Command>) -> Option<Vec<Command>> {
+    (commands.len() <= MAX_COMMANDS).then_some(commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{simulate, AimModel, DirectModel, ModelRegistry, SubmarineModel},
+        parse, Command, Direction,
+    };
+
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::Forward,
+        Direction::Back,
+        Direction::Up,
+        Direction::Down,
+    ];
+
+    /// The length of the shortest course to the target that uses at most two
+    /// commands with distances up to 12, found by trying all of them.
+    fn search(model: &dyn SubmarineModel, position: i64, depth: i64) -> Option<usize> {
+        let commands: Vec<_> = DIRECTIONS
+            .iter()
+            .flat_map(|&direction| {
+                (1..=12).map(move |distance| Command {
+                    direction,
+                    distance,
+                })
+            })
+            .collect();
+        let reaches = |course: &[Command]| {
+            let submarine = simulate(model, course).unwrap();
+            (submarine.position, submarine.depth) == (position, depth)
+        };
+
+        if reaches(&[]) {
+            return Some(0);
+        }
+        if commands.iter().any(|&command| reaches(&[command])) {
+            return Some(1);
+        }
+        commands
+            .iter()
+            .any(|&first| commands.iter().any(|&second| reaches(&[first, second])))
+            .then_some(2)
+    }
+
+    #[test]
+    fn test_planner_agrees_with_simulator() {
+        for model in [&DirectModel as &dyn SubmarineModel, &AimModel] {
+            for position in -6..=6 {
+                for depth in -6..=6 {
+                    let course = model.plan(position, depth).unwrap();
+                    let submarine = simulate(model, &course).unwrap();
+                    assert_eq!((submarine.position, submarine.depth), (position, depth));
+                    assert!(course.iter().all(|command| command.distance > 0));
+
+                    // Anything longer than two commands is only minimal if
+                    // nothing shorter exists.
+                    match search(model, position, depth) {
+                        Some(length) => assert_eq!(course.len(), length),
+                        None => assert_eq!(course.len(), 3),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_short_aim_courses() {
+        // Every target reached by travelling, aiming and then travelling
+        // again, with distances from across the range a command can cover.
+        let max = i32::MAX as i64;
+        let magnitudes = [1, 2, 3, 10, 97, 1000, 4096, 65_537, 1 << 20, max - 1, max];
+        let distances: Vec<i64> = magnitudes
+            .iter()
+            .flat_map(|&distance| [distance, -distance])
+            .collect();
+        for &aim in &distances {
+            for &last in &distances {
+                for first in distances.iter().copied().chain([0]) {
+                    let (position, depth) = (first + last, aim * last);
+                    let course = AimModel.plan(position, depth).unwrap();
+                    let submarine = simulate(&AimModel, &course).unwrap();
+                    assert_eq!((submarine.position, submarine.depth), (position, depth));
+                    let length = if first == 0 { 2 } else { 3 };
+                    assert!(course.len() <= length, "{:?}", (position, depth));
+                }
+            }
+        }
+
+        // Travelling back first lets the last move be long enough to reach a
+        // depth that no move from the surface could.
+        let course = AimModel.plan(0, 1 << 40).unwrap();
+        assert_eq!(
+            course,
+            parse("back 1024\ndown 1073741824\nforward 1024\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_plan_by_name() {
+        let registry = ModelRegistry::default();
+        let course = registry.plan("aim", 15, 60).unwrap();
+        assert_eq!(course, parse("down 4\nforward 15\n").unwrap());
+
+        let course = registry.plan("aim", 7, -10).unwrap();
+        assert_eq!(course, parse("forward 6\nup 10\nforward 1\n").unwrap());
+        assert_eq!(simulate(&AimModel, &course).unwrap().product(), Ok(-70));
+
+        let course = registry.plan("direct", -3, 0).unwrap();
+        assert_eq!(course, parse("back 3\n").unwrap());
+        assert_eq!(registry.plan("missing", 1, 1), None);
+
+        // A single move can't cover 2^31.
+        let course = registry.plan("direct", -(1 << 31), 0).unwrap();
+        assert_eq!(course, parse("back 2147483647\nback 1\n").unwrap());
+
+        // Every distance fits in a command, even though the position is as far
+        // as one command can go.
+        let target = (i32::MAX as i64, -1);
+        let course = registry.plan("aim", target.0, target.1).unwrap();
+        assert_eq!(
+            course,
+            parse("forward 2147483646\nup 1\nforward 1\n").unwrap()
+        );
+        let submarine = simulate(&AimModel, &course).unwrap();
+        assert_eq!((submarine.position, submarine.depth), target);
+    }
+
+    #[test]
+    fn test_distant_targets() {
+        let max = i32::MAX as i64;
+        let targets = [
+            (max * 3 + 2, 0),
+            (-(max * 2), max + 1),
+            (max + 5, -3),
+            (0, 1 << 40),
+            (-7, max * 4 + 12),
+        ];
+        for model in [&DirectModel as &dyn SubmarineModel, &AimModel] {
+            for (position, depth) in targets {
+                let course = model.plan(position, depth).unwrap();
+                let submarine = simulate(model, &course).unwrap();
+                assert_eq!((submarine.position, submarine.depth), (position, depth));
+            }
+        }
+
+        // The aim model reaches depths that would take the direct model more
+        // commands than a course can hold.
+        for (position, depth) in [(5, i64::MIN), (i64::MIN / max, i64::MAX), (-1, max * max)] {
+            let course = AimModel.plan(position, depth).unwrap();
+            let submarine = simulate(&AimModel, &course).unwrap();
+            assert_eq!((submarine.position, submarine.depth), (position, depth));
+        }
+
+        // Each command can move at most `max`, so the direct model needs as
+        // many as it takes to cover each distance.
+        assert_eq!(DirectModel.plan(max * 3 + 2, -max).unwrap().len(), 5);
+        assert_eq!(AimModel.plan(max * 3 + 2, 0).unwrap().len(), 4);
+
+        // These would take more commands than a course can hold.
+        assert_eq!(DirectModel.plan(i64::MAX, 0), None);
+        assert_eq!(AimModel.plan(i64::MIN, 1), None);
+    }
+}
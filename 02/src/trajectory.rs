@@ -0,0 +1,188 @@
+use std::fmt::Write;
+
+use aoc_common::svg::Svg;
+
+use crate::{
+    model::{Overflow, Submarine, SubmarineModel},
+    Command,
+};
+
+const COLOURS: [&str; 4] = ["#1f77b4", "#d62728", "#2ca02c", "#9467bd"];
+
+/// Every state the submarine passed through while following a course.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trajectory {
+    /// The name of the model that produced the trajectory.
+    pub model: String,
+    /// The course that was followed.
+    pub commands: Vec<Command>,
+    /// The state at the surface, followed by the state after each command.
+    pub states: Vec<Submarine>,
+}
+
+impl Trajectory {
+    /// Runs every command through `model`, keeping each state along the way.
+    pub fn record(model: &dyn SubmarineModel, commands: &[Command]) -> Result<Self, Overflow> {
+        let mut states = Vec::with_capacity(commands.len() + 1);
+        states.push(Submarine::default());
+        for (step, command) in commands.iter().enumerate() {
+            let last = states[states.len() - 1];
+            let next = model.apply(last, command).ok_or(Overflow::Command {
+                step,
+                command: *command,
+            })?;
+            states.push(next);
+        }
+
+        Ok(Self {
+            model: model.name().to_owned(),
+            commands: commands.to_vec(),
+            states,
+        })
+    }
+
+    pub fn last(&self) -> Submarine {
+        self.states[self.states.len() - 1]
+    }
+
+    /// The index of the first state where the two trajectories disagree about
+    /// where the submarine is. Differences in aim alone are ignored, since
+    /// not every model uses it.
+    pub fn first_divergence(&self, other: &Trajectory) -> Option<usize> {
+        self.states
+            .iter()
+            .zip(&other.states)
+            .position(|(a, b)| (a.position, a.depth) != (b.position, b.depth))
+    }
+}
+
+/// Writes trajectories as CSV, with a row for every state of every
+/// trajectory. Each row gives the command that led to the state, which is
+/// empty for the starting state.
+pub fn to_csv(trajectories: &[Trajectory]) -> String {
+    let mut csv = String::from("model,step,command,position,depth,aim\n");
+    for trajectory in trajectories {
+        let model = csv_field(&trajectory.model);
+        for (step, state) in trajectory.states.iter().enumerate() {
+            let command = match step {
+                0 => String::new(),
+                _ => trajectory.commands[step - 1].to_string(),
+            };
+            writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                model, step, command, state.position, state.depth, state.aim
+            )
+            .unwrap();
+        }
+    }
+    csv
+}
+
+/// Quotes `text` if it contains anything that would otherwise end the field.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+/// Plots the depth of each trajectory against its horizontal position, with
+/// depth increasing down the chart. All of the trajectories share the same
+/// axes so that they can be compared.
+pub fn render_svg(trajectories: &[Trajectory], width: u32, height: u32) -> String {
+    let states = || {
+        trajectories
+            .iter()
+            .flat_map(|trajectory| &trajectory.states)
+    };
+    let min_position = states().map(|state| state.position).min().unwrap_or(0);
+    let max_position = states().map(|state| state.position).max().unwrap_or(0);
+    let min_depth = states().map(|state| state.depth).min().unwrap_or(0);
+    let max_depth = states().map(|state| state.depth).max().unwrap_or(0);
+
+    let mut svg = Svg::new(width, height);
+    let (plot_width, plot_height) = (svg.plot_width(), svg.plot_height());
+    let scale = |value: i64, min: i64, max: i64, size: f64| match max as f64 - min as f64 {
+        range if range > 0.0 => (value as f64 - min as f64) / range * size,
+        _ => 0.0,
+    };
+
+    for (trajectory, colour) in trajectories.iter().zip(COLOURS.iter().cycle()) {
+        let points = trajectory.states.iter().map(|state| {
+            let x = scale(state.position, min_position, max_position, plot_width);
+            let y = scale(state.depth, min_depth, max_depth, plot_height);
+            (x, y)
+        });
+        svg.polyline(points, colour, 1.5);
+        svg.legend(&trajectory.model, colour);
+    }
+
+    svg.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{AimModel, DirectModel},
+        parse,
+        trajectory::{render_svg, to_csv, Trajectory},
+        EXAMPLE,
+    };
+
+    #[test]
+    fn test_record() {
+        let commands = parse(EXAMPLE).unwrap();
+        let direct = Trajectory::record(&DirectModel, &commands).unwrap();
+        let aim = Trajectory::record(&AimModel, &commands).unwrap();
+
+        assert_eq!(direct.states.len(), commands.len() + 1);
+        assert_eq!(direct.last().product(), Ok(150));
+        assert_eq!(aim.last().product(), Ok(900));
+
+        // The models first disagree after `down 5`, which only changes the
+        // depth directly in the direct model.
+        assert_eq!(direct.first_divergence(&aim), Some(2));
+        assert_eq!(direct.first_divergence(&direct), None);
+    }
+
+    #[test]
+    fn test_export() {
+        let commands = parse("forward 5\ndown 5\nforward 8\n").unwrap();
+        let trajectories = [
+            Trajectory::record(&DirectModel, &commands).unwrap(),
+            Trajectory::record(&AimModel, &commands).unwrap(),
+        ];
+
+        let csv = to_csv(&trajectories);
+        let rows: Vec<_> = csv.lines().collect();
+        assert_eq!(rows.len(), 9);
+        assert_eq!(rows[0], "model,step,command,position,depth,aim");
+        assert_eq!(rows[1], "direct,0,,0,0,0");
+        assert_eq!(rows[4], "direct,3,forward 8,13,5,0");
+        assert_eq!(rows[8], "aim,3,forward 8,13,40,5");
+
+        let svg = render_svg(&trajectories, 800, 300);
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert!(svg.contains(">aim</text>"));
+        // The deepest point is the aim model's final state, at the bottom
+        // right of the plot.
+        assert!(svg.contains("760.0,260.0\""));
+    }
+
+    #[test]
+    fn test_export_escapes_model_names() {
+        let commands = parse("forward 5\n").unwrap();
+        let mut trajectory = Trajectory::record(&DirectModel, &commands).unwrap();
+        trajectory.model = "a \"<fast>\", & deep".to_owned();
+
+        let csv = to_csv(&[trajectory.clone()]);
+        assert_eq!(
+            csv.lines().nth(2),
+            Some("\"a \"\"<fast>\"\", & deep\",1,forward 5,5,0,0")
+        );
+        let svg = render_svg(&[trajectory], 800, 300);
+        assert!(svg.contains(">a &quot;&lt;fast&gt;&quot;, &amp; deep</text>"));
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    iter,
+};
+
+use aoc_common::{Answer, ParseError, Span};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A binary number of any width.
+///
+/// Columns are numbered from the least significant bit, so column 0 is the
+/// last digit of the number as written.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bits {
+    /// The value in 64 bit words, least significant first. Bits above `len`
+    /// are always zero.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bits {
+    /// A number `len` bits wide with every bit clear.
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// Parses a line of `0`s and `1`s, most significant bit first.
+    pub fn parse(line: Span) -> Result<Self, ParseError> {
+        let len = line.as_str().len();
+        let mut bits = Self::zeros(len);
+        for (i, (bit, span)) in line.chars().enumerate() {
+            match bit {
+                '0' => (),
+                '1' => bits.set(len - 1 - i, true),
+                _ => return Err(span.error("`0` or `1`")),
+            }
+        }
+        Ok(bits)
+    }
+
+    /// The number of columns, including any leading zeros.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Panics if `column` is not less than `len`.
+    pub fn get(&self, column: usize) -> bool {
+        assert!(column < self.len, "column {} out of range", column);
+        self.words[column / WORD_BITS] >> (column % WORD_BITS) & 1 == 1
+    }
+
+    /// Panics if `column` is not less than `len`.
+    pub fn set(&mut self, column: usize, bit: bool) {
+        assert!(column < self.len, "column {} out of range", column);
+        let flag = 1 << (column % WORD_BITS);
+        let word = &mut self.words[column / WORD_BITS];
+        match bit {
+            true => *word |= flag,
+            false => *word &= !flag,
+        }
+    }
+
+    /// The columns that hold a 1, from least to most significant.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(i * WORD_BITS + bit)
+            })
+        })
+    }
+
+    /// The value as a `u64`, if it is small enough.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.words.split_first() {
+            Some((&low, high)) if high.iter().all(|&word| word == 0) => Some(low),
+            Some(_) => None,
+            None => Some(0),
+        }
+    }
+
+    /// Multiplies two numbers, giving a result wide enough to hold any
+    /// product of numbers with their widths.
+    pub fn mul(&self, other: &Bits) -> Bits {
+        let len = self.len + other.len;
+        let mut words = vec![0; self.words.len() + other.words.len()];
+        for (i, &a) in self.words.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.words.iter().enumerate() {
+                let total = words[i + j] as u128 + a as u128 * b as u128 + carry;
+                words[i + j] = total as u64;
+                carry = total >> WORD_BITS;
+            }
+            words[i + other.words.len()] = carry as u64;
+        }
+
+        // The product always fits in `len` bits, so any extra words are zero.
+        words.truncate(len.div_ceil(WORD_BITS));
+        Self { words, len }
+    }
+
+    /// Formats the value in decimal.
+    pub fn to_decimal(&self) -> String {
+        // Each division by 10^19 yields the next 19 decimal digits.
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+        let mut words = self.words.clone();
+        let mut chunks = Vec::new();
+        while words.iter().any(|&word| word != 0) {
+            let mut remainder = 0;
+            for word in words.iter_mut().rev() {
+                let current = remainder << WORD_BITS | *word as u128;
+                *word = (current / CHUNK) as u64;
+                remainder = current % CHUNK;
+            }
+            chunks.push(remainder as u64);
+        }
+
+        match chunks.split_last() {
+            Some((first, rest)) => rest.iter().rev().fold(first.to_string(), |decimal, chunk| {
+                format!("{}{:019}", decimal, chunk)
+            }),
+            None => "0".to_owned(),
+        }
+    }
+}
+
+/// Orders numbers by value, and then by width for numbers that only differ in
+/// their leading zeros.
+impl Ord for Bits {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let word = |bits: &Bits, i: usize| bits.words.get(i).copied().unwrap_or(0);
+        let num_words = self.words.len().max(other.words.len());
+        (0..num_words)
+            .rev()
+            .map(|i| word(self, i).cmp(&word(other, i)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+            .then(self.len.cmp(&other.len))
+    }
+}
+
+impl PartialOrd for Bits {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Writes the number in binary with all of its columns, as it was parsed.
+impl Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for column in (0..self.len).rev() {
+            write!(f, "{}", self.get(column) as u8)?;
+        }
+        Ok(())
+    }
+}
+
+/// Numbers that don't fit in a `u64` are given as decimal text.
+impl From<Bits> for Answer {
+    fn from(bits: Bits) -> Self {
+        match bits.to_u64() {
+            Some(value) => Answer::Unsigned(value),
+            None => Answer::Text(bits.to_decimal()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{Answer, Span};
+
+    use crate::bits::Bits;
+
+    fn bits(text: &str) -> Bits {
+        Bits::parse(Span::new(text)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let value = bits("00101");
+        assert_eq!((value.len(), value.to_u64()), (5, Some(5)));
+        assert!(value.get(0) && !value.get(1) && value.get(2));
+        assert_eq!(value.to_string(), "00101");
+
+        let text = format!("1{}1", "0".repeat(98));
+        let wide = bits(&text);
+        assert_eq!(wide.len(), 100);
+        assert!(wide.get(99) && wide.get(0) && !wide.get(64));
+        assert_eq!(wide.to_u64(), None);
+        assert_eq!(wide.to_string(), text);
+
+        assert_eq!(wide.ones().collect::<Vec<_>>(), vec![0, 99]);
+        assert!(bits("0110") < bits("1000"));
+        assert!(bits("011") < bits("0011") && bits("0011") < bits("100"));
+        assert!(wide > bits(&"1".repeat(99)));
+
+        let error = Bits::parse(Span::new("0120")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected `0` or `1`, found `2`"
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut a = Bits::zeros(71);
+        a.set(70, true);
+        a.set(0, true);
+        a.set(2, true);
+        let mut b = Bits::zeros(65);
+        b.set(64, true);
+        b.set(0, true);
+        b.set(1, true);
+
+        // (2^70 + 5) * (2^64 + 3)
+        let product = a.mul(&b);
+        assert_eq!(product.len(), 136);
+        assert_eq!(
+            product.to_decimal(),
+            "21778071482940061665289983458153947201551"
+        );
+
+        let small = bits("1011").mul(&bits("110"));
+        assert_eq!((small.len(), small.to_u64()), (7, Some(66)));
+        assert_eq!(Answer::from(small), Answer::Unsigned(66));
+        assert_eq!(Bits::zeros(3).to_decimal(), "0");
+
+        let mut power = Bits::zeros(101);
+        power.set(100, true);
+        assert_eq!(
+            Answer::from(power),
+            Answer::Text("1267650600228229401496703205376".to_owned())
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Which bit wins when a column has as many 1s as 0s.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TiePolicy {
+    PreferOne,
+    PreferZero,
+    /// Treat a tie as an error rather than picking a side.
+    Error,
+}
+
+/// Whether the more or less common bit in a column is kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Keep {
+    MostCommon,
+    LeastCommon,
+}
+
+/// How to pick a bit for a column from how many values have each bit there.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BitCriteria {
+    pub keep: Keep,
+    pub tie: TiePolicy,
+}
+
+impl BitCriteria {
+    /// The criteria for the gamma rate and the oxygen generator rating.
+    pub const MOST_COMMON: Self = Self {
+        keep: Keep::MostCommon,
+        tie: TiePolicy::PreferOne,
+    };
+
+    /// The criteria for the CO2 scrubber rating.
+    pub const LEAST_COMMON: Self = Self {
+        keep: Keep::LeastCommon,
+        tie: TiePolicy::PreferZero,
+    };
+
+    /// The bit to keep in `column`, given how many values have each bit
+    /// there.
+    pub fn bit(&self, column: usize, ones: usize, zeros: usize) -> Result<u32, CriteriaError> {
+        match (ones.cmp(&zeros), self.keep) {
+            (Ordering::Equal, _) => match self.tie {
+                TiePolicy::PreferOne => Ok(1),
+                TiePolicy::PreferZero => Ok(0),
+                TiePolicy::Error => Err(CriteriaError::Tie { column }),
+            },
+            (Ordering::Greater, Keep::MostCommon) | (Ordering::Less, Keep::LeastCommon) => Ok(1),
+            _ => Ok(0),
+        }
+    }
+}
+
+impl Display for BitCriteria {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.keep {
+            Keep::MostCommon => write!(f, "most common bit")?,
+            Keep::LeastCommon => write!(f, "least common bit")?,
+        }
+        match self.tie {
+            TiePolicy::PreferOne => write!(f, ", ties keep 1"),
+            TiePolicy::PreferZero => write!(f, ", ties keep 0"),
+            TiePolicy::Error => write!(f, ", ties are an error"),
+        }
+    }
+}
+
+/// Why the bit criteria couldn't be applied to a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CriteriaError {
+    /// The column had as many 1s as 0s and ties are an error.
+    Tie { column: usize },
+    /// The criteria chose a bit that none of the remaining values have in the
+    /// column, which can happen when they all share the same bit there.
+    NoMatch { column: usize },
+}
+
+impl Display for CriteriaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tie { column } => write!(f, "column {} has as many 1s as 0s", column),
+            Self::NoMatch { column } => {
+                write!(f, "no value has the chosen bit in column {}", column)
+            }
+        }
+    }
+}
+
+impl Error for CriteriaError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::criteria::{BitCriteria, CriteriaError, Keep, TiePolicy};
+
+    #[test]
+    fn test_bit_criteria() {
+        assert_eq!(BitCriteria::MOST_COMMON.bit(0, 7, 5), Ok(1));
+        assert_eq!(BitCriteria::MOST_COMMON.bit(0, 3, 3), Ok(1));
+        assert_eq!(BitCriteria::LEAST_COMMON.bit(0, 7, 5), Ok(0));
+        assert_eq!(BitCriteria::LEAST_COMMON.bit(0, 3, 3), Ok(0));
+        assert_eq!(BitCriteria::LEAST_COMMON.bit(0, 0, 4), Ok(1));
+
+        let strict = BitCriteria {
+            keep: Keep::LeastCommon,
+            tie: TiePolicy::Error,
+        };
+        assert_eq!(strict.bit(2, 1, 2), Ok(1));
+        assert_eq!(strict.bit(2, 2, 2), Err(CriteriaError::Tie { column: 2 }));
+        assert_eq!(strict.to_string(), "least common bit, ties are an error");
+    }
+}
@@ -0,0 +1,268 @@
+use std::ops::Range;
+
+use aoc_common::{ParseError, Solver, Span};
+
+use crate::{
+    bits::Bits,
+    criteria::{BitCriteria, CriteriaError, Keep, TiePolicy},
+};
+
+pub mod bits;
+pub mod criteria;
+pub mod report;
+
+pub struct Data {
+    /// The values in ascending order, so that values which share their
+    /// leading bits are next to each other.
+    pub values: Vec<Bits>,
+    pub line_length: usize,
+    /// How many of the values have a 1 in each column.
+    pub ones: Vec<usize>,
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<Data, ParseError> {
+    let input = Span::new(input_str);
+    let first_line = input.lines().next().ok_or_else(|| input.error("a binary number"))?;
+    let line_length = first_line.as_str().len();
+
+    let mut values = input
+        .lines()
+        .map(|line| {
+            if line.as_str().len() != line_length {
+                return Err(line.error(format!("a {} bit binary number", line_length)));
+            }
+            Bits::parse(line)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    values.sort_unstable();
+    let ones = count_ones(&values, line_length);
+
+    Ok(Data{ values, line_length, ones })
+}
+
+/// Counts the 1s in every column in a single pass over the values.
+pub fn count_ones(values: &[Bits], line_length: usize) -> Vec<usize> {
+    let mut ones = vec![0; line_length];
+    for value in values {
+        for column in value.ones() {
+            ones[column] += 1;
+        }
+    }
+    ones
+}
+
+/// The gamma and epsilon rates, which are made up of the most and least
+/// common bit in each column. Ties are settled for the gamma rate by `tie`,
+/// and the epsilon rate always takes the other bit.
+pub fn power_rates(data: &Data, tie: TiePolicy) -> Result<(Bits, Bits), CriteriaError> {
+    let criteria = BitCriteria { keep: Keep::MostCommon, tie };
+    let mut gamma_rate = Bits::zeros(data.line_length);
+    let mut epsilon_rate = Bits::zeros(data.line_length);
+    for (i, &ones) in data.ones.iter().enumerate() {
+        let bit = criteria.bit(i, ones, data.values.len() - ones)?;
+        gamma_rate.set(i, bit == 1);
+        epsilon_rate.set(i, bit == 0);
+    }
+    Ok((gamma_rate, epsilon_rate))
+}
+
+/// One round of narrowing down the candidates for a rating.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RatingStep {
+    pub column: usize,
+    pub ones: usize,
+    pub zeros: usize,
+    /// The bit the criteria chose for the column.
+    pub bit: u32,
+    /// The indices into `Data::values` of the values left after this round.
+    pub candidates: Range<usize>,
+}
+
+/// Narrows the values down one column at a time, starting from the most
+/// significant, until a single value is left.
+///
+/// Because the values are sorted, those still in the running are always a
+/// contiguous range, with the ones that have a 0 in the next column at the
+/// start of it. Each round is then a binary search for where the 1s begin
+/// rather than a pass over every value.
+pub fn extract_rating(data: &Data, criteria: BitCriteria) -> Result<&Bits, CriteriaError> {
+    narrow(data, criteria, |_| ())
+}
+
+/// `extract_rating`, calling `on_step` after each round.
+pub(crate) fn narrow(
+    data: &Data,
+    criteria: BitCriteria,
+    mut on_step: impl FnMut(&RatingStep),
+) -> Result<&Bits, CriteriaError> {
+    let mut candidates = 0..data.values.len();
+    for column in (0..data.line_length).rev() {
+        if candidates.len() <= 1 { break; }
+        let values = &data.values[candidates.clone()];
+        let zeros = values.partition_point(|value| !value.get(column));
+        let ones = values.len() - zeros;
+        let bit = criteria.bit(column, ones, zeros)?;
+
+        let split = candidates.start + zeros;
+        candidates = match bit {
+            0 => candidates.start..split,
+            _ => split..candidates.end,
+        };
+        on_step(&RatingStep { column, ones, zeros, bit, candidates: candidates.clone() });
+        if candidates.is_empty() {
+            return Err(CriteriaError::NoMatch { column });
+        }
+    }
+    Ok(&data.values[candidates.start])
+}
+
+pub fn part1(data: &Data) -> Result<Bits, CriteriaError> {
+    let (gamma_rate, epsilon_rate) = power_rates(data, TiePolicy::PreferOne)?;
+    Ok(gamma_rate.mul(&epsilon_rate))
+}
+
+pub fn part2(data: &Data) -> Result<Bits, CriteriaError> {
+    let oxygen_rating = extract_rating(data, BitCriteria::MOST_COMMON)?;
+    let scrubber_rating = extract_rating(data, BitCriteria::LEAST_COMMON)?;
+    Ok(oxygen_rating.mul(scrubber_rating))
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 3;
+    const NAME: &'static str = "Binary Diagnostic";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Data;
+    type Part1 = Result<Bits, CriteriaError>;
+    type Part2 = Result<Bits, CriteriaError>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::{
+        bits::Bits,
+        count_ones,
+        criteria::{BitCriteria, CriteriaError, Keep, TiePolicy},
+        extract_rating, parse, part1, part2, power_rates, Puzzle, EXAMPLE,
+    };
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+
+    #[test]
+    fn test_wide_report() {
+        // Each line of the example repeated eight times over, making 40 bit
+        // numbers with the same column counts as the original.
+        let input: String = EXAMPLE
+            .lines()
+            .map(|line| line.repeat(8) + "\n")
+            .collect();
+        let data = parse(&input).unwrap();
+        assert_eq!(data.line_length, 40);
+        assert_eq!(part1(&data).unwrap().to_decimal(), "249081490409220780423750");
+        assert_eq!(part2(&data).unwrap().to_decimal(), "289337084818791815643750");
+    }
+
+    /// Filters the values a column at a time in the simplest possible way.
+    fn filter_rating(values: &[Bits], keep_most_common: bool) -> Bits {
+        let mut values = values.to_vec();
+        for column in (0..values[0].len()).rev() {
+            if values.len() <= 1 {
+                break;
+            }
+            let ones = values.iter().filter(|value| value.get(column)).count();
+            let most_common = ones * 2 >= values.len();
+            values.retain(|value| value.get(column) == (most_common == keep_most_common));
+        }
+        values.swap_remove(0)
+    }
+
+    #[test]
+    fn test_against_filtering() {
+        // A pseudo-random report, with enough rows that most columns are
+        // needed to settle each rating.
+        let mut state: u64 = 12345;
+        let lines: String = (0..3000)
+            .map(|_| {
+                (0..70)
+                    .map(|_| {
+                        state = state
+                            .wrapping_mul(6364136223846793005)
+                            .wrapping_add(1442695040888963407);
+                        match state >> 63 {
+                            1 => '1',
+                            _ => '0',
+                        }
+                    })
+                    .chain(['\n'])
+                    .collect::<String>()
+            })
+            .collect();
+        let data = parse(&lines).unwrap();
+
+        let ones = count_ones(&data.values, data.line_length);
+        for (column, &count) in ones.iter().enumerate() {
+            assert_eq!(count, data.values.iter().filter(|value| value.get(column)).count());
+        }
+
+        let oxygen = extract_rating(&data, BitCriteria::MOST_COMMON).unwrap();
+        assert_eq!(*oxygen, filter_rating(&data.values, true));
+        let scrubber = extract_rating(&data, BitCriteria::LEAST_COMMON).unwrap();
+        assert_eq!(*scrubber, filter_rating(&data.values, false));
+    }
+
+    #[test]
+    fn test_tie_policies() {
+        let data = parse(EXAMPLE).unwrap();
+        let criteria = |keep, tie| BitCriteria { keep, tie };
+
+        // The last round for the oxygen generator rating is between 10110
+        // and 10111.
+        let rating = extract_rating(&data, criteria(Keep::MostCommon, TiePolicy::PreferZero));
+        assert_eq!(rating.unwrap().to_u64(), Some(22));
+        let rating = extract_rating(&data, criteria(Keep::MostCommon, TiePolicy::Error));
+        assert_eq!(rating, Err(CriteriaError::Tie { column: 0 }));
+
+        // 01111 and 01010 tie in the middle column.
+        let rating = extract_rating(&data, criteria(Keep::LeastCommon, TiePolicy::PreferOne));
+        assert_eq!(rating.unwrap().to_u64(), Some(15));
+
+        let (gamma, epsilon) = power_rates(&data, TiePolicy::Error).unwrap();
+        assert_eq!((gamma.to_u64(), epsilon.to_u64()), (Some(22), Some(9)));
+        let data = parse("01\n10\n").unwrap();
+        assert_eq!(
+            power_rates(&data, TiePolicy::Error),
+            Err(CriteriaError::Tie { column: 0 })
+        );
+
+        // Both values start with 1, so the least common bit there is 0.
+        let data = parse("10\n11\n").unwrap();
+        let rating = extract_rating(&data, BitCriteria::LEAST_COMMON);
+        assert_eq!(rating, Err(CriteriaError::NoMatch { column: 1 }));
+        assert_eq!(part2(&data), Err(CriteriaError::NoMatch { column: 1 }));
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    bits::Bits,
+    criteria::{BitCriteria, CriteriaError},
+    narrow, Data, RatingStep,
+};
+
+/// Candidates are listed individually in the report when there are at most
+/// this many of them, and just counted otherwise.
+const MAX_LISTED: usize = 4;
+
+/// How many values have each bit in a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnStats {
+    pub column: usize,
+    pub ones: usize,
+    pub zeros: usize,
+}
+
+/// Every round of extracting a rating, so that it can be checked by hand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RatingAudit {
+    pub criteria: BitCriteria,
+    pub steps: Vec<RatingStep>,
+    pub rating: Result<Bits, CriteriaError>,
+}
+
+impl RatingAudit {
+    pub fn new(data: &Data, criteria: BitCriteria) -> Self {
+        let mut steps = Vec::new();
+        let rating = narrow(data, criteria, |step| steps.push(step.clone())).cloned();
+        Self {
+            criteria,
+            steps,
+            rating,
+        }
+    }
+}
+
+/// The per-column counts of a diagnostic report, along with how each of its
+/// ratings was reached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagnosticReport {
+    /// The values, in the sorted order that the candidate ranges refer to.
+    pub values: Vec<Bits>,
+    /// The stats for every column, most significant first.
+    pub columns: Vec<ColumnStats>,
+    pub oxygen_generator: RatingAudit,
+    pub co2_scrubber: RatingAudit,
+}
+
+impl DiagnosticReport {
+    pub fn new(data: &Data, oxygen_generator: BitCriteria, co2_scrubber: BitCriteria) -> Self {
+        let columns = data
+            .ones
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(column, &ones)| ColumnStats {
+                column,
+                ones,
+                zeros: data.values.len() - ones,
+            })
+            .collect();
+
+        Self {
+            values: data.values.clone(),
+            columns,
+            oxygen_generator: RatingAudit::new(data, oxygen_generator),
+            co2_scrubber: RatingAudit::new(data, co2_scrubber),
+        }
+    }
+
+    fn write_audit(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        name: &str,
+        audit: &RatingAudit,
+    ) -> fmt::Result {
+        writeln!(f, "{} rating ({}):", name, audit.criteria)?;
+        for step in &audit.steps {
+            write!(
+                f,
+                "  column {}: {} ones, {} zeros, keep {}, {} left",
+                step.column,
+                step.ones,
+                step.zeros,
+                step.bit,
+                step.candidates.len()
+            )?;
+            if !step.candidates.is_empty() && step.candidates.len() <= MAX_LISTED {
+                let listed: Vec<_> = self.values[step.candidates.clone()]
+                    .iter()
+                    .map(Bits::to_string)
+                    .collect();
+                write!(f, " ({})", listed.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+        match &audit.rating {
+            Ok(rating) => writeln!(f, "  rating: {} ({})", rating, rating.to_decimal()),
+            Err(err) => writeln!(f, "  error: {}", err),
+        }
+    }
+}
+
+impl Display for DiagnosticReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Values: {}", self.values.len())?;
+        for stats in &self.columns {
+            writeln!(
+                f,
+                "Column {}: {} ones, {} zeros",
+                stats.column, stats.ones, stats.zeros
+            )?;
+        }
+        self.write_audit(f, "Oxygen generator", &self.oxygen_generator)?;
+        self.write_audit(f, "CO2 scrubber", &self.co2_scrubber)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        criteria::{BitCriteria, CriteriaError, Keep, TiePolicy},
+        parse,
+        report::{ColumnStats, DiagnosticReport},
+        EXAMPLE,
+    };
+
+    #[test]
+    fn test_report() {
+        let data = parse(EXAMPLE).unwrap();
+        let report =
+            DiagnosticReport::new(&data, BitCriteria::MOST_COMMON, BitCriteria::LEAST_COMMON);
+        assert_eq!(
+            report.columns[0],
+            ColumnStats {
+                column: 4,
+                ones: 7,
+                zeros: 5
+            }
+        );
+        assert_eq!(report.columns.len(), 5);
+
+        let left: Vec<_> = report
+            .oxygen_generator
+            .steps
+            .iter()
+            .map(|step| step.candidates.len())
+            .collect();
+        assert_eq!(left, vec![7, 4, 3, 2, 1]);
+        let left: Vec<_> = report
+            .co2_scrubber
+            .steps
+            .iter()
+            .map(|step| step.candidates.len())
+            .collect();
+        assert_eq!(left, vec![5, 2, 1]);
+
+        let text = report.to_string();
+        assert!(text.contains("Column 4: 7 ones, 5 zeros\n"));
+        assert!(text.contains("Oxygen generator rating (most common bit, ties keep 1):\n"));
+        assert!(text.contains("  column 0: 1 ones, 1 zeros, keep 1, 1 left (10111)\n"));
+        assert!(text.contains("  column 3: 2 ones, 3 zeros, keep 1, 2 left (01010, 01111)\n"));
+        assert!(text.contains("  rating: 01010 (10)\n"));
+    }
+
+    #[test]
+    fn test_report_errors() {
+        let data = parse(EXAMPLE).unwrap();
+        let strict = BitCriteria {
+            keep: Keep::MostCommon,
+            tie: TiePolicy::Error,
+        };
+        let report = DiagnosticReport::new(&data, strict, BitCriteria::LEAST_COMMON);
+        assert_eq!(report.oxygen_generator.steps.len(), 4);
+        assert_eq!(
+            report.oxygen_generator.rating,
+            Err(CriteriaError::Tie { column: 0 })
+        );
+        assert!(report
+            .to_string()
+            .contains("  error: column 0 has as many 1s as 0s\n"));
+    }
+}
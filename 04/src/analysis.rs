@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use crate::{Bingo, Board};
+
+/// How a board would fare against the draw on its own, ignoring every other
+/// board.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoardAnalysis {
+    pub board: usize,
+    /// The index into `Bingo::numbers` of the draw that first completes one
+    /// of the board's winning patterns.
+    pub earliest_win: Option<usize>,
+    /// For a board that never wins, the numbers that are never drawn from the
+    /// pattern that comes closest to being completed. Empty for a board that
+    /// does win.
+    pub missing: Vec<u32>,
+}
+
+impl BoardAnalysis {
+    pub fn can_win(&self) -> bool {
+        self.earliest_win.is_some()
+    }
+}
+
+impl Bingo {
+    /// The numbers making up each pattern that would win `board` under the
+    /// game's rules, sorted and without duplicates.
+    fn winning_sets(&self, board: &Board) -> Vec<Vec<u32>> {
+        let (width, height) = (board.cells.width(), board.cells.height());
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.patterns(width, height))
+            .map(|pattern| {
+                let mut numbers: Vec<_> = pattern.iter().map(|&cell| board.cells[cell]).collect();
+                numbers.sort_unstable();
+                numbers.dedup();
+                numbers
+            })
+            .collect()
+    }
+
+    /// Works out when each board could win with the numbers as drawn.
+    ///
+    /// Boards don't affect each other, so this is the draw each board wins
+    /// on in `play`. Boards that never win are given the fewest numbers that
+    /// would have to be added to the draw for them to win.
+    pub fn analyze(&self) -> Vec<BoardAnalysis> {
+        let mut first_draw = HashMap::new();
+        for (draw, &number) in self.numbers.iter().enumerate() {
+            first_draw.entry(number).or_insert(draw);
+        }
+
+        self.boards
+            .iter()
+            .enumerate()
+            .map(|(index, board)| {
+                let sets = self.winning_sets(board);
+                let earliest_win = sets
+                    .iter()
+                    .filter_map(|set| {
+                        let draws = set.iter().map(|number| first_draw.get(number));
+                        draws
+                            .collect::<Option<Vec<_>>>()?
+                            .into_iter()
+                            .max()
+                            .copied()
+                    })
+                    .min();
+                let missing = match earliest_win {
+                    Some(_) => Vec::new(),
+                    None => sets
+                        .iter()
+                        .map(|set| {
+                            set.iter()
+                                .filter(|number| !first_draw.contains_key(number))
+                                .copied()
+                                .collect::<Vec<_>>()
+                        })
+                        .min_by_key(|missing| missing.len())
+                        .unwrap_or_default(),
+                };
+
+                BoardAnalysis {
+                    board: index,
+                    earliest_win,
+                    missing,
+                }
+            })
+            .collect()
+    }
+
+    /// A draw that makes `board` the first, and only, board to win, if there
+    /// is one.
+    ///
+    /// The draw is the numbers from one of the board's winning patterns, so
+    /// the board wins on the final number. A pattern can be used as long as
+    /// no other board has a winning pattern made up entirely of its numbers.
+    /// The shortest such pattern is chosen. There is no draw if every pattern
+    /// would also win another board, as happens when two boards are the same.
+    pub fn rig_for(&self, board: usize) -> Option<Vec<u32>> {
+        let others: Vec<_> = self
+            .boards
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| index != board)
+            .map(|(_, other)| self.winning_sets(other))
+            .collect();
+
+        let mut sets = self.winning_sets(&self.boards[board]);
+        sets.sort_by_key(|set| set.len());
+        sets.into_iter().find(|set| {
+            others
+                .iter()
+                .flatten()
+                .all(|other| !other.iter().all(|number| set.binary_search(number).is_ok()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, rules::WinRule, EXAMPLE};
+
+    #[test]
+    fn test_analyze() {
+        let mut bingo = parse(EXAMPLE).unwrap();
+        let results = bingo.play();
+        for analysis in bingo.analyze() {
+            let (_, win) = results.rank_of(analysis.board).unwrap();
+            assert_eq!(analysis.earliest_win, Some(win.draw));
+            assert!(analysis.missing.is_empty());
+        }
+
+        // Without 7 and 24, all three boards first complete a line when 12 is
+        // drawn.
+        bingo.numbers.retain(|&number| number != 24 && number != 7);
+        let analyses = bingo.analyze();
+        assert!(analyses
+            .iter()
+            .all(|analysis| analysis.earliest_win == Some(16)));
+
+        bingo.numbers.truncate(12);
+        let missing: Vec<_> = bingo
+            .analyze()
+            .into_iter()
+            .map(|analysis| (analysis.can_win(), analysis.missing))
+            .collect();
+        assert_eq!(
+            missing,
+            vec![(false, vec![7]), (false, vec![13, 18]), (false, vec![24])]
+        );
+    }
+
+    #[test]
+    fn test_rig() {
+        let mut bingo = parse(EXAMPLE).unwrap();
+        bingo.rules.push(WinRule::Diagonals);
+        for board in 0..bingo.boards.len() {
+            let draw = bingo.rig_for(board).unwrap();
+            let mut rigged = bingo.clone();
+            rigged.numbers = draw.clone();
+            let results = rigged.play();
+            assert_eq!(results.wins.len(), 1);
+            assert_eq!(results.first().unwrap().board, board);
+            assert_eq!(results.first().unwrap().draw, draw.len() - 1);
+        }
+
+        // Boards made up of the same lines can never beat each other, even when
+        // the numbers are laid out differently.
+        let bingo = parse("1\n\n1 2\n3 4\n\n1 2\n3 4\n\n4 3\n2 1\n").unwrap();
+        assert_eq!(bingo.rig_for(0), None);
+        assert_eq!(bingo.rig_for(2), None);
+    }
+}
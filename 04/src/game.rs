@@ -0,0 +1,261 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use aoc_common::grid::Position;
+
+use crate::{Bingo, Board};
+
+/// When and how well a board won.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Win {
+    /// The index of the board in `Bingo::boards`.
+    pub board: usize,
+    /// The index into `Bingo::numbers` of the draw that won the board.
+    pub draw: usize,
+    pub number: u32,
+    /// The winning number multiplied by the total of the unmarked cells.
+    pub score: u32,
+}
+
+/// The outcome of playing a game through to the end.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GameResults {
+    /// Every board that won, in the order they won. Boards that win on the
+    /// same draw are in the order they appear in the input.
+    pub wins: Vec<Win>,
+    /// The boards that were still without a win when the numbers ran out,
+    /// in the order they appear in the input.
+    pub unfinished: Vec<usize>,
+}
+
+impl GameResults {
+    pub fn first(&self) -> Option<&Win> {
+        self.wins.first()
+    }
+
+    pub fn last(&self) -> Option<&Win> {
+        self.wins.last()
+    }
+
+    /// The board that won in position `rank`, counting from 0.
+    pub fn nth(&self, rank: usize) -> Option<&Win> {
+        self.wins.get(rank)
+    }
+
+    /// Where `board` finished, and how it won, if it did.
+    pub fn rank_of(&self, board: usize) -> Option<(usize, &Win)> {
+        self.wins
+            .iter()
+            .enumerate()
+            .find(|(_, win)| win.board == board)
+    }
+}
+
+/// Returned when the numbers run out without any board winning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoWinner;
+
+impl Display for NoWinner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no board wins")
+    }
+}
+
+impl Error for NoWinner {}
+
+impl Bingo {
+    /// Every cell on every board holding each number, in board order.
+    pub fn number_index(&self) -> HashMap<u32, Vec<(usize, Position)>> {
+        let mut index: HashMap<u32, Vec<(usize, Position)>> = HashMap::new();
+        for (board_index, board) in self.boards.iter().enumerate() {
+            for (position, &number) in board.cells.positions().zip(board.cells.cells()) {
+                index
+                    .entry(number)
+                    .or_default()
+                    .push((board_index, position));
+            }
+        }
+        index
+    }
+
+    /// Draws every number in turn, starting from clear boards, and records
+    /// each board as it wins. The game stops early once every board has won.
+    pub fn play(&self) -> GameResults {
+        let mut game = Game::new(self);
+        while !game.is_over() && game.draw().is_some() {}
+        game.into_results()
+    }
+}
+
+/// A game being played one draw at a time.
+///
+/// Each draw goes straight to the cells holding it through
+/// `Bingo::number_index`, so only the boards it appears on are touched.
+pub struct Game<'a> {
+    bingo: &'a Bingo,
+    boards: Vec<Board>,
+    index: HashMap<u32, Vec<(usize, Position)>>,
+    next_draw: usize,
+    won: Vec<bool>,
+    wins: Vec<Win>,
+}
+
+impl<'a> Game<'a> {
+    /// A game with every board cleared and nothing drawn yet.
+    pub fn new(bingo: &'a Bingo) -> Self {
+        let mut boards = bingo.boards.clone();
+        boards.iter_mut().for_each(Board::clear);
+        Self {
+            bingo,
+            boards,
+            index: bingo.number_index(),
+            next_draw: 0,
+            won: vec![false; bingo.boards.len()],
+            wins: Vec::new(),
+        }
+    }
+
+    /// Draws the next number, returning the boards that won with it, or
+    /// `None` if every number has already been drawn.
+    pub fn draw(&mut self) -> Option<&[Win]> {
+        let draw = self.next_draw;
+        let &number = self.bingo.numbers.get(draw)?;
+        self.next_draw += 1;
+        let first_new_win = self.wins.len();
+
+        let cells = self.index.get(&number).map_or(&[][..], Vec::as_slice);
+        // A number can appear more than once on a board, so all of its cells
+        // are marked before the board is scored.
+        for cells in cells.chunk_by(|a, b| a.0 == b.0) {
+            let board_index = cells[0].0;
+            if self.won[board_index] {
+                continue;
+            }
+            let board = &mut self.boards[board_index];
+            let mut is_won = false;
+            for &(_, position) in cells {
+                is_won |= board.mark(position, &self.bingo.rules);
+            }
+
+            if is_won {
+                self.won[board_index] = true;
+                self.wins.push(Win {
+                    board: board_index,
+                    draw,
+                    number,
+                    score: number * board.unmarked_total(),
+                });
+            }
+        }
+
+        Some(&self.wins[first_new_win..])
+    }
+
+    /// The boards as they stand after the draws so far. Boards stop being
+    /// marked once they have won.
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    /// How many numbers have been drawn.
+    pub fn num_drawn(&self) -> usize {
+        self.next_draw
+    }
+
+    /// The wins so far, in the order they happened.
+    pub fn wins(&self) -> &[Win] {
+        &self.wins
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.wins.len() == self.boards.len()
+    }
+
+    pub fn into_results(self) -> GameResults {
+        let unfinished = (0..self.won.len())
+            .filter(|&board| !self.won[board])
+            .collect();
+        GameResults {
+            wins: self.wins,
+            unfinished,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::{Game, Win},
+        parse,
+        rules::WinRule,
+        EXAMPLE,
+    };
+
+    #[test]
+    fn test_play() {
+        let bingo = parse(EXAMPLE).unwrap();
+        let results = bingo.play();
+        assert_eq!(results.wins.len(), 3);
+        assert!(results.unfinished.is_empty());
+        assert_eq!(
+            results.first(),
+            Some(&Win {
+                board: 2,
+                draw: 11,
+                number: 24,
+                score: 4512,
+            })
+        );
+        assert_eq!(results.nth(1).map(|win| win.board), Some(0));
+        assert_eq!(
+            results.last().map(|win| (win.draw, win.score)),
+            Some((14, 1924))
+        );
+        assert_eq!(results.rank_of(0).map(|(rank, _)| rank), Some(1));
+        assert_eq!(results.nth(3), None);
+    }
+
+    #[test]
+    fn test_step_through() {
+        let bingo = parse(EXAMPLE).unwrap();
+        let mut game = Game::new(&bingo);
+        for _ in 0..11 {
+            assert_eq!(game.draw(), Some(&[][..]));
+        }
+        let wins = game.draw().unwrap();
+        assert_eq!(wins.iter().map(|win| win.board).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(game.num_drawn(), 12);
+        assert!(game.boards()[2].marked_cells()[(0, 0)]);
+
+        while game.draw().is_some() {}
+        assert_eq!(game.num_drawn(), bingo.numbers.len());
+        assert!(game.is_over());
+        assert_eq!(game.into_results(), bingo.play());
+    }
+
+    #[test]
+    fn test_unfinished_boards() {
+        let mut bingo = parse(EXAMPLE).unwrap();
+        bingo.rules = vec![WinRule::Blackout];
+        bingo.numbers.truncate(20);
+        let results = bingo.play();
+        assert_eq!(results.wins, vec![]);
+        assert_eq!(results.unfinished, vec![0, 1, 2]);
+
+        // Boards that win on the same draw keep their input order, and a
+        // number that appears twice on a board marks both cells.
+        let bingo = parse("5,1\n\n1 5\n5 2\n\n5 1\n3 4\n").unwrap();
+        let results = bingo.play();
+        let wins: Vec<_> = results
+            .wins
+            .iter()
+            .map(|win| (win.board, win.draw))
+            .collect();
+        assert_eq!(wins, vec![(0, 1), (1, 1)]);
+        assert_eq!(results.first().unwrap().score, 2);
+        assert_eq!(results.last().unwrap().score, 3 + 4);
+    }
+}
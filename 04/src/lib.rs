@@ -0,0 +1,202 @@
+use aoc_common::{grid::Position, parse::comma_separated, Grid, ParseError, Solver, Span};
+
+use crate::{game::NoWinner, rules::WinRule};
+
+pub mod analysis;
+pub mod game;
+pub mod replay;
+pub mod rules;
+
+#[derive(Clone)]
+pub struct Board {
+    cells: Grid<u32>,
+    marked_cells: Grid<bool>,
+}
+
+impl Board {
+    fn new(cells: Grid<u32>) -> Self {
+        let marked_cells = cells.map(|_| false);
+        Board {
+            cells,
+            marked_cells,
+        }
+    }
+
+    pub fn cells(&self) -> &Grid<u32> {
+        &self.cells
+    }
+
+    pub fn marked_cells(&self) -> &Grid<bool> {
+        &self.marked_cells
+    }
+
+    /// Marks the cell at `position`, returning whether that wins the board
+    /// under any of `rules`.
+    fn mark(&mut self, position: Position, rules: &[WinRule]) -> bool {
+        self.marked_cells[position] = true;
+
+        rules.iter().any(|rule| rule.is_won(&self.marked_cells, position))
+    }
+
+    fn unmarked_total(&self) -> u32 {
+        self.cells
+            .cells()
+            .iter()
+            .zip(self.marked_cells.cells())
+            .filter(|(_cell, marked)| !**marked)
+            .map(|(cell, _marked)| cell)
+            .sum()
+    }
+
+    fn clear(&mut self) {
+        self.marked_cells.cells_mut().fill(false);
+    }
+}
+
+#[derive(Clone)]
+pub struct Bingo {
+    pub numbers: Vec<u32>,
+    pub boards: Vec<Board>,
+    /// The ways a board can win, any one of which is enough.
+    pub rules: Vec<WinRule>,
+}
+
+impl Bingo {
+    pub fn clear_boards(&mut self) {
+        self.boards.iter_mut().for_each(|board| board.clear());
+    }
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<Bingo, ParseError> {
+    let input = Span::new(input_str);
+    let mut sections = input.sections();
+
+    let numbers_line = sections
+        .next()
+        .ok_or_else(|| input.error("a list of drawn numbers"))?;
+    let numbers = comma_separated(numbers_line, "a drawn number")?;
+
+    let mut boards: Vec<Board> = Vec::new();
+    for section in sections {
+        let mut width = None;
+        let mut board_cells = Vec::new();
+        for line in section.lines() {
+            let row_start = board_cells.len();
+            for number_str in line.split_whitespace() {
+                board_cells.push(number_str.parse("a board number")?);
+            }
+
+            let row_width = board_cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let expected = format!("a row of {} numbers", width);
+                    return Err(line.error(expected));
+                }
+                _ => (),
+            }
+        }
+
+        let width = width.ok_or_else(|| section.error("a bingo board"))?;
+        boards.push(Board::new(Grid::new(board_cells, width)));
+    }
+
+    if boards.is_empty() {
+        return Err(input.end().error("a bingo board"));
+    }
+
+    Ok(Bingo { numbers, boards, rules: WinRule::standard() })
+}
+
+pub fn part1(bingo: &Bingo) -> Result<u32, NoWinner> {
+    bingo.play().first().map(|win| win.score).ok_or(NoWinner)
+}
+
+pub fn part2(bingo: &Bingo) -> Result<u32, NoWinner> {
+    bingo.play().last().map(|win| win.score).ok_or(NoWinner)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 4;
+    const NAME: &'static str = "Giant Squid";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Bingo;
+    type Part1 = Result<u32, NoWinner>;
+    type Part2 = Result<u32, NoWinner>;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{expected::assert_example, runner::solve, Part};
+
+    use crate::{game::NoWinner, parse, part1, part2, rules::WinRule, Puzzle, EXAMPLE};
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+
+    #[test]
+    fn test_non_square_boards() {
+        let input = "\
+6,3,5,2,1,4
+
+1 2 3
+4 5 6
+
+7 8
+9 6
+3 5
+";
+        let bingo = parse(input).unwrap();
+        // The first board wins on 3 with its last column, leaving 1 + 2 + 4 + 5
+        // unmarked. The second wins on 5 with its bottom row.
+        assert_eq!(part1(&bingo), Ok(3 * 12));
+        assert_eq!(part2(&bingo), Ok(5 * (7 + 8 + 9)));
+    }
+
+    #[test]
+    fn test_win_rules() {
+        let mut bingo = parse(EXAMPLE).unwrap();
+        bingo.rules = vec![WinRule::Diagonals];
+        assert_eq!((part1(&bingo), part2(&bingo)), (Ok(494), Ok(76)));
+
+        // The other two boards only have all four corners marked once every
+        // number on them has been drawn.
+        bingo.rules = vec![WinRule::FourCorners];
+        assert_eq!((part1(&bingo), part2(&bingo)), (Ok(3262), Ok(0)));
+
+        bingo.rules.extend(WinRule::standard());
+        assert_eq!(part1(&bingo), Ok(3262));
+    }
+
+    #[test]
+    fn test_no_winner() {
+        let input = "1,4\n\n1 2\n3 4\n";
+        assert_eq!(part1(&parse(input).unwrap()), Err(NoWinner));
+
+        let error = solve::<Puzzle>(input, &[Part::Two]).unwrap_err();
+        assert_eq!(error.to_string(), "no board wins");
+    }
+}
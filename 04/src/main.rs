@@ -1,158 +1,56 @@
-use std::collections::HashSet;
+use std::{env, io, time::Duration};
 
-struct Board {
-    num_rows: usize,
-    num_cols: usize,
-    cells: Vec<u32>,
-    marked_cells: Vec<bool>,
-}
-
-impl Board {
-    fn new(cells: Vec<u32>, num_rows: usize) -> Self {
-        let marked_cells = vec![false; cells.len()];
-        let num_cols = cells.len() / num_rows;
-        Board {
-            num_rows,
-            num_cols,
-            cells,
-            marked_cells,
-        }
-    }
-
-    fn mark(&mut self, number: u32) -> bool {
-        let index = self.cells
-            .iter()
-            .position(|cell| *cell == number);
-
-        let index = match index {
-            Some(index) => index,
-            None => return false,
-        };
-
-        self.marked_cells[index] = true;
-
-        let col = index % self.num_cols;
-        let row = index / self.num_rows;
-
-        self.test_col(col) || self.test_row(row)
-    }
-
-    fn test_row(&self, row: usize) -> bool {
-        let start = row * self.num_cols;
-        let end = start + self.num_cols;
-        let mut filled = true;
-        for index in start..end {
-            filled = filled && self.marked_cells[index];
-        }
-        filled
-    }
-
-    fn test_col(&self, col: usize) -> bool {
-        let mut filled = true;
-        for i in 0..self.num_rows {
-            let index = self.num_cols * i + col;
-            filled = filled && self.marked_cells[index];
-        }
-        filled
-    }
+use aoc_common::{input::Source, runner::exit_with_error, Solver};
+use giant_squid::{
+    replay::{replay, ReplayOptions},
+    Puzzle,
+};
 
-    fn unmarked_total(&self) -> u32 {
-        self.marked_cells
-            .iter()
-            .enumerate()
-            .filter(|(_i, marked)| !**marked)
-            .fold(0, |acc, (i, _marked)| acc + self.cells[i])
-    }
-
-    fn clear(&mut self) {
-        self.marked_cells.fill(false);
-    }
-}
-
-struct Bingo {
-    numbers: Vec<u32>,
-    boards: Vec<Board>,
-}
-
-impl Bingo {
-    fn clear_boards(&mut self) {
-        self.boards.iter_mut().for_each(|board| board.clear());
-    }
-}
-
-fn get_data() -> Bingo {
-    let input_str = include_str!("./input.txt");
-    let mut input_iter = input_str.lines();
-
-    let numbers: Vec<u32> = input_iter
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|number_str| number_str.parse().unwrap())
-        .collect();
-
-    input_iter.next();
-
-    let mut boards: Vec<Board> = Vec::new();
-    let mut rows = 0;
-    let mut board_cells = Vec::new();
-    for line in input_iter {
-        match line {
-            "" => {
-                boards.push(Board::new(board_cells, rows));
-                board_cells = Vec::new();
-                rows = 0;
-            }
-            _ => {
-                line
-                    .split_whitespace()
-                    .for_each(|number_str| {
-                        let number = number_str.parse().unwrap();
-                        board_cells.push(number);
-                    });
-                rows += 1;
+/// With `--replay`, plays the game in the terminal a draw at a time instead of
+/// printing the answers. Accepts `[--steps N] [--delay MS] [--example | PATH]`.
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.iter().any(|arg| arg == "--replay") {
+        aoc_common::runner::run_day::<Puzzle>();
+        return;
+    }
+
+    let mut options = ReplayOptions::default();
+    let mut example = false;
+    let mut path = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--replay" => {}
+            "--steps" => {
+                let steps = value(iter.next(), "--steps");
+                options.steps = Some(steps);
             }
+            "--delay" => options.delay = Duration::from_millis(value(iter.next(), "--delay")),
+            "--example" => example = true,
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => exit_with_error(format!("unexpected argument `{}`", arg)),
         }
     }
 
-    boards.push(Board::new(board_cells, rows));
-    Bingo { numbers, boards }
-}
-
-fn part1(bingo: &mut Bingo) {
-    for number in &bingo.numbers {
-        for board in bingo.boards.iter_mut() {
-            if board.mark(*number) {
-                println!("First BINGO! score: {}", number * board.unmarked_total());
-                return
-            }
-        }
+    if example && path.is_some() {
+        exit_with_error("--example cannot be used with an input path");
     }
-}
-
-fn part2(bingo: &mut Bingo) {
-    let num_boards = bingo.boards.len();
-    let mut complete = HashSet::new();
-
-    for number in &bingo.numbers {
-        for (i, board) in bingo.boards.iter_mut().enumerate() {
-            if complete.contains(&i) { continue; }
 
-            if board.mark(*number) {
-                complete.insert(i);
-
-                if complete.len() == num_boards {
-                    println!("Last BINGO! score: {}", number * board.unmarked_total());
-                    return;
-                }
-            }
-        }
-    }
+    let default = if example {
+        Puzzle::EXAMPLE
+    } else {
+        Puzzle::INPUT
+    };
+    let input = Source::from_arg(path)
+        .read(default)
+        .unwrap_or_else(|err| exit_with_error(format!("Failed to read input: {}", err)));
+    let bingo = Puzzle::parse(&input).unwrap_or_else(|err| exit_with_error(err));
+    replay(&bingo, options, &mut io::stdout().lock()).unwrap_or_else(|err| exit_with_error(err));
 }
 
-fn main() {
-    let mut data = get_data();
-    part1(&mut data);
-    data.clear_boards();
-    part2(&mut data);
+fn value<T: std::str::FromStr>(arg: Option<&String>, flag: &str) -> T {
+    arg.and_then(|value| value.parse().ok())
+        .unwrap_or_else(|| exit_with_error(format!("{} requires a number", flag)))
 }
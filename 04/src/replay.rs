@@ -0,0 +1,216 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    game::{Game, Win},
+    Bingo,
+};
+
+const BOARDS_PER_ROW: usize = 5;
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+const MARKED: &str = "\x1b[7m";
+const LATEST: &str = "\x1b[1;7;33m";
+const RESET: &str = "\x1b[0m";
+
+/// Settings for `replay`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplayOptions {
+    /// Stop after this many draws, rather than at the end of the game.
+    pub steps: Option<usize>,
+    /// How long to wait between draws. With no delay every frame is written
+    /// one after the other instead of redrawing the screen.
+    pub delay: Duration,
+}
+
+impl Default for ReplayOptions {
+    fn default() -> Self {
+        Self {
+            steps: None,
+            delay: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Plays the game a draw at a time, writing a frame to `out` after each one.
+/// The replay ends once every board has won, the numbers run out, or the
+/// number of steps in `options` have been drawn.
+pub fn replay(bingo: &Bingo, options: ReplayOptions, out: &mut impl Write) -> io::Result<()> {
+    let mut game = Game::new(bingo);
+    let steps = options.steps.unwrap_or(usize::MAX);
+    while game.num_drawn() < steps && !game.is_over() {
+        let Some(new_wins) = game.draw() else {
+            break;
+        };
+        let new_wins = new_wins.to_vec();
+
+        if !options.delay.is_zero() {
+            write!(out, "{}", CLEAR_SCREEN)?;
+        }
+        write!(out, "{}", render_frame(bingo, &game, &new_wins))?;
+        out.flush()?;
+        thread::sleep(options.delay);
+    }
+    Ok(())
+}
+
+/// Draws every board, with marked cells in reverse video and the number that
+/// was just drawn highlighted, followed by any boards that won with it.
+pub fn render_frame(bingo: &Bingo, game: &Game, new_wins: &[Win]) -> String {
+    let mut frame = String::new();
+    let latest = game
+        .num_drawn()
+        .checked_sub(1)
+        .map(|draw| bingo.numbers[draw]);
+    match latest {
+        Some(number) => writeln!(
+            frame,
+            "Draw {} of {}: {}",
+            game.num_drawn(),
+            bingo.numbers.len(),
+            number
+        ),
+        None => writeln!(frame, "Nothing drawn yet"),
+    }
+    .unwrap();
+
+    let cell_width = bingo
+        .boards
+        .iter()
+        .flat_map(|board| board.cells().cells())
+        .map(|number| number.to_string().len())
+        .max()
+        .unwrap_or(1);
+
+    let boards: Vec<_> = game.boards().iter().enumerate().collect();
+    for row in boards.chunks(BOARDS_PER_ROW) {
+        writeln!(frame).unwrap();
+        // Each board is padded to the same width so the titles line up.
+        let board_width = |width: usize| width * (cell_width + 1) - 1;
+        let titles: Vec<_> = row
+            .iter()
+            .map(|&(index, board)| {
+                let title = match game.wins().iter().position(|win| win.board == index) {
+                    Some(rank) => format!("#{} won {}", index + 1, ordinal(rank + 1)),
+                    None => format!("#{}", index + 1),
+                };
+                format!("{:<1$}", title, board_width(board.cells().width()))
+            })
+            .collect();
+        writeln!(frame, "{}", titles.join("   ").trim_end()).unwrap();
+
+        let height = row
+            .iter()
+            .map(|(_, board)| board.cells().height())
+            .max()
+            .unwrap_or(0);
+        for y in 0..height {
+            let lines: Vec<_> = row
+                .iter()
+                .map(|&(_, board)| {
+                    let width = board.cells().width();
+                    if y >= board.cells().height() {
+                        return " ".repeat(board_width(width));
+                    }
+                    let cells: Vec<_> = (0..width)
+                        .map(|x| {
+                            let number = board.cells()[(x, y)];
+                            let text = format!("{:>1$}", number, cell_width);
+                            match (board.marked_cells()[(x, y)], Some(number) == latest) {
+                                (true, true) => format!("{}{}{}", LATEST, text, RESET),
+                                (true, false) => format!("{}{}{}", MARKED, text, RESET),
+                                (false, _) => text,
+                            }
+                        })
+                        .collect();
+                    cells.join(" ")
+                })
+                .collect();
+            writeln!(frame, "{}", lines.join("   ").trim_end()).unwrap();
+        }
+    }
+
+    for win in new_wins {
+        writeln!(
+            frame,
+            "\nBoard #{} wins {} with {}! Score: {}",
+            win.board + 1,
+            ordinal(game.wins().iter().position(|other| other == win).unwrap() + 1),
+            win.number,
+            win.score
+        )
+        .unwrap();
+    }
+    frame
+}
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        parse,
+        replay::{ordinal, replay, ReplayOptions, CLEAR_SCREEN, LATEST, MARKED, RESET},
+        EXAMPLE,
+    };
+
+    fn run(steps: Option<usize>) -> String {
+        let bingo = parse(EXAMPLE).unwrap();
+        let options = ReplayOptions {
+            steps,
+            delay: Duration::ZERO,
+        };
+        let mut out = Vec::new();
+        replay(&bingo, options, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_replay() {
+        let output = run(Some(2));
+        assert_eq!(output.matches("Draw ").count(), 2);
+        assert!(output.starts_with("Draw 1 of 27: 7\n\n#1               #2               #3\n"));
+        // 7 is on every board. By the second frame it is no longer the latest
+        // draw, so it's shown as an ordinary marked cell.
+        assert!(output.contains(&format!("{} 7{}", LATEST, RESET)));
+        assert!(output.contains(&format!("{} 7{}", MARKED, RESET)));
+        assert!(output.contains(&format!("{} 4{}", LATEST, RESET)));
+        assert!(!output.contains(CLEAR_SCREEN));
+
+        // The game ends on the 15th draw, when the last board wins.
+        let output = run(None);
+        assert_eq!(output.matches("Draw ").count(), 15);
+        assert!(output.contains("\nBoard #3 wins 1st with 24! Score: 4512\n"));
+        assert!(output.contains("#1 won 2nd"));
+        assert!(output.ends_with("\nBoard #2 wins 3rd with 13! Score: 1924\n"));
+    }
+
+    #[test]
+    fn test_ordinal() {
+        let ordinals: Vec<_> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 101, 111]
+            .into_iter()
+            .map(ordinal)
+            .collect();
+        assert_eq!(
+            ordinals,
+            vec![
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "101st",
+                "111th"
+            ]
+        );
+    }
+}
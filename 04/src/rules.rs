@@ -0,0 +1,170 @@
+use aoc_common::{grid::Position, Grid};
+
+/// A pattern of marked cells that wins a board.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WinRule {
+    /// Any complete row.
+    Rows,
+    /// Any complete column.
+    Columns,
+    /// Either complete diagonal. Only square boards have diagonals.
+    Diagonals,
+    /// All four corners.
+    FourCorners,
+    /// Every cell on the board.
+    Blackout,
+    /// Every cell that is set in the mask. Boards with different dimensions
+    /// to the mask can never win with it.
+    Mask(Grid<bool>),
+}
+
+impl WinRule {
+    /// The rules from the puzzle, where only rows and columns count.
+    pub fn standard() -> Vec<WinRule> {
+        vec![WinRule::Rows, WinRule::Columns]
+    }
+
+    /// Whether the marked cells satisfy the rule, given that `last` was the
+    /// most recent cell to be marked.
+    ///
+    /// Every rule is checked as soon as each cell is marked, so rows, columns
+    /// and diagonals only need to be checked where they pass through `last`.
+    pub fn is_won(&self, marked: &Grid<bool>, last: Position) -> bool {
+        let (col, row) = last;
+        let (width, height) = (marked.width(), marked.height());
+        match self {
+            Self::Rows => marked.row(row).iter().all(|&marked| marked),
+            Self::Columns => marked.column(col).all(|&marked| marked),
+            Self::Diagonals => {
+                let main = || (0..width).all(|i| marked[(i, i)]);
+                let anti = || (0..width).all(|i| marked[(width - 1 - i, i)]);
+                width == height && ((col == row && main()) || (col + row == width - 1 && anti()))
+            }
+            Self::FourCorners => [
+                (0, 0),
+                (width - 1, 0),
+                (0, height - 1),
+                (width - 1, height - 1),
+            ]
+            .iter()
+            .all(|&corner| marked[corner]),
+            Self::Blackout => marked.cells().iter().all(|&marked| marked),
+            Self::Mask(mask) => {
+                (mask.width(), mask.height()) == (width, height)
+                    && mask
+                        .cells()
+                        .iter()
+                        .zip(marked.cells())
+                        .all(|(&required, &marked)| !required || marked)
+            }
+        }
+    }
+
+    /// Every set of cells that satisfies the rule on a board of the given
+    /// size.
+    pub fn patterns(&self, width: usize, height: usize) -> Vec<Vec<Position>> {
+        match self {
+            Self::Rows => (0..height)
+                .map(|y| (0..width).map(|x| (x, y)).collect())
+                .collect(),
+            Self::Columns => (0..width)
+                .map(|x| (0..height).map(|y| (x, y)).collect())
+                .collect(),
+            Self::Diagonals if width == height => vec![
+                (0..width).map(|i| (i, i)).collect(),
+                (0..width).map(|i| (width - 1 - i, i)).collect(),
+            ],
+            Self::Diagonals => Vec::new(),
+            Self::FourCorners => vec![vec![
+                (0, 0),
+                (width - 1, 0),
+                (0, height - 1),
+                (width - 1, height - 1),
+            ]],
+            Self::Blackout => vec![(0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .collect()],
+            Self::Mask(mask) if (mask.width(), mask.height()) == (width, height) => {
+                vec![mask
+                    .positions()
+                    .filter(|&position| mask[position])
+                    .collect()]
+            }
+            Self::Mask(_) => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{grid::Position, Grid};
+
+    use crate::rules::WinRule;
+
+    fn marked(width: usize, height: usize, cells: &[Position]) -> Grid<bool> {
+        Grid::from_fn(width, height, |position| cells.contains(&position))
+    }
+
+    #[test]
+    fn test_lines() {
+        let row = marked(3, 2, &[(0, 1), (1, 1), (2, 1)]);
+        assert!(WinRule::Rows.is_won(&row, (1, 1)));
+        assert!(!WinRule::Columns.is_won(&row, (1, 1)));
+
+        let column = marked(3, 2, &[(2, 0), (2, 1)]);
+        assert!(WinRule::Columns.is_won(&column, (2, 0)));
+        assert!(!WinRule::Rows.is_won(&column, (2, 0)));
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let main = marked(3, 3, &[(0, 0), (1, 1), (2, 2)]);
+        assert!(WinRule::Diagonals.is_won(&main, (1, 1)));
+        let anti = marked(3, 3, &[(2, 0), (1, 1), (0, 2)]);
+        assert!(WinRule::Diagonals.is_won(&anti, (0, 2)));
+        assert!(!WinRule::Diagonals.is_won(&anti, (0, 0)));
+
+        let wide = marked(3, 2, &[(0, 0), (1, 1)]);
+        assert!(!WinRule::Diagonals.is_won(&wide, (1, 1)));
+    }
+
+    #[test]
+    fn test_patterns() {
+        let corners = [(0, 0), (3, 0), (0, 1), (3, 1)];
+        let board = marked(4, 2, &corners);
+        assert!(WinRule::FourCorners.is_won(&board, (3, 1)));
+        assert!(!WinRule::Blackout.is_won(&board, (3, 1)));
+        assert!(WinRule::Blackout.is_won(&Grid::filled(4, 2, true), (0, 0)));
+
+        // An X across a 3x3 board.
+        let cross = Grid::from_fn(3, 3, |(x, y)| x == y || x + y == 2);
+        let rule = WinRule::Mask(cross.clone());
+        assert!(rule.is_won(&cross, (1, 1)));
+        assert!(!rule.is_won(&marked(3, 3, &[(0, 0), (1, 1), (2, 2)]), (1, 1)));
+        assert!(!rule.is_won(&Grid::filled(4, 3, true), (1, 1)));
+    }
+
+    #[test]
+    fn test_patterns_match_rules() {
+        let cross = Grid::from_fn(3, 3, |(x, y)| x == y || x + y == 2);
+        let rules = [
+            WinRule::Rows,
+            WinRule::Columns,
+            WinRule::Diagonals,
+            WinRule::FourCorners,
+            WinRule::Blackout,
+            WinRule::Mask(cross),
+        ];
+        for (width, height) in [(3, 3), (4, 2)] {
+            for rule in &rules {
+                for pattern in rule.patterns(width, height) {
+                    let board = marked(width, height, &pattern);
+                    let last = pattern[pattern.len() - 1];
+                    assert!(rule.is_won(&board, last), "{:?} {:?}", rule, pattern);
+                }
+            }
+        }
+        assert_eq!(WinRule::Rows.patterns(4, 2).len(), 2);
+        assert_eq!(WinRule::Diagonals.patterns(4, 2), Vec::<Vec<_>>::new());
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use aoc_common::{ParseError, Solver, Span};
+
+pub struct CoordPair {
+    pub from: (i32, i32),
+    pub to: (i32, i32),
+}
+
+impl CoordPair {
+    pub fn iter(&self) -> PointIter {
+        PointIter::new(self.from, self.to)
+    }
+}
+
+pub struct PointIter {
+    next: (i32, i32),
+    end: (i32, i32),
+    step_x: i32,
+    step_y: i32,
+}
+
+impl PointIter {
+    fn new(start: (i32, i32), end: (i32, i32)) -> Self {
+        let step_x = (end.0 - start.0).signum();
+        let step_y = (end.1 - start.1).signum();
+        PointIter {
+            next: start,
+            end,
+            step_x,
+            step_y,
+        }
+    }
+}
+
+impl Iterator for PointIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<<Self>::Item> {
+        let curr = self.next;
+        self.next = (
+            self.next.0 + self.step_x,
+            self.next.1 + self.step_y,
+        );
+        if (self.step_x > 0 && curr.0 > self.end.0) || (self.step_x < 0 && curr.0 < self.end.0) {
+            return None;
+        }
+        if (self.step_y > 0 && curr.1 > self.end.1) || (self.step_y < 0 && curr.1 < self.end.1) {
+            return None;
+        }
+        Some(curr)
+    }
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+fn parse_coord(span: Span) -> Result<(i32, i32), ParseError> {
+    let (x, y) = span.split_once(",")?;
+    Ok((x.parse("an x coordinate")?, y.parse("a y coordinate")?))
+}
+
+pub fn parse(input: &str) -> Result<Vec<CoordPair>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            let (left, right) = line.split_once(" -> ")?;
+            let from = parse_coord(left)?;
+            let to = parse_coord(right)?;
+            Ok(CoordPair { from, to })
+        })
+        .collect()
+}
+
+pub fn part1(data: &[CoordPair]) -> i32 {
+    let mut intersections = HashMap::new();
+
+    for line in data {
+        if line.from.0 == line.to.0 || line.from.1 == line.to.1 {
+            for point in line.iter() {
+                *intersections.entry(point).or_insert(0) += 1;
+            }
+        }
+    }
+
+    intersections
+        .values()
+        .fold(0, |acc, val| acc + (*val > 1) as i32)
+}
+
+pub fn part2(data: &[CoordPair]) -> i32 {
+    let mut intersections = HashMap::new();
+
+    for line in data {
+        for point in line.iter() {
+            *intersections.entry(point).or_insert(0) += 1;
+        }
+    }
+
+    intersections
+        .values()
+        .fold(0, |acc, val| acc + (*val > 1) as i32)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 5;
+    const NAME: &'static str = "Hydrothermal Venture";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Vec<CoordPair>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_common::{ParseError, Solver, Span};
+
+#[derive(Clone)]
+pub struct Lanternfish {
+    circle_buffer: [u64; 7],
+    pointer: usize,
+    new_queue: [u64; 2],
+}
+
+impl Lanternfish {
+    pub fn new(circle_buffer: [u64; 7]) -> Self {
+        Self {
+            circle_buffer,
+            pointer: 6,
+            new_queue: [0; 2],
+        }
+    }
+
+    pub fn tick(&mut self) {
+        let to_add = self.new_queue[0];
+        self.new_queue[0] = self.new_queue[1];
+        self.new_queue[1] = self.circle_buffer[self.pointer];
+        self.circle_buffer[self.pointer] += to_add;
+        self.pointer = (self.pointer + 1) % self.circle_buffer.len();
+    }
+
+    pub fn count(&self) -> u64 {
+        self.circle_buffer.iter().sum::<u64>() + self.new_queue.iter().sum::<u64>()
+    }
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<Lanternfish, ParseError> {
+    let mut circle_buffer = [0; 7];
+    for timer in Span::new(input_str).trim_end().split(",") {
+        let n: usize = timer.parse("a timer")?;
+        *circle_buffer
+            .get_mut(n)
+            .ok_or_else(|| timer.error("a timer between 0 and 6"))? += 1;
+    }
+
+    Ok(Lanternfish::new(circle_buffer))
+}
+
+pub fn lanternfish_after_days(lanternfish: &Lanternfish, num_days: usize) -> u64 {
+    let mut lanternfish = lanternfish.clone();
+    for _ in 0..=num_days {
+        lanternfish.tick();
+    }
+    lanternfish.count()
+}
+
+pub fn part1(lanternfish: &Lanternfish) -> u64 {
+    lanternfish_after_days(lanternfish, 80)
+}
+
+pub fn part2(lanternfish: &Lanternfish) -> u64 {
+    lanternfish_after_days(lanternfish, 256)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 6;
+    const NAME: &'static str = "Lanternfish";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Lanternfish;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
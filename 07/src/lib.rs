@@ -0,0 +1,80 @@
+use aoc_common::{parse::comma_separated, ParseError, Solver, Span};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<Vec<i32>, ParseError> {
+    let mut data: Vec<i32> = comma_separated(Span::new(input_str).trim_end(), "a crab position")?;
+    data.sort();
+    Ok(data)
+}
+
+// Writing out the equation f(b) = sumᵢ₌₁₋ₙ(|b - aᵢ|) and differentiating shows
+// that the optimal solution must lie at the median of the starting points.
+pub fn part1(crabs: &[i32]) -> i32 {
+    let index = crabs.len() / 2;
+    let offset = 1 - crabs.len() % 2;
+    let median = (crabs[index - offset] + crabs[index]) / 2;
+
+    crabs.iter().fold(0, |total_fuel_cost, curr_pos| {
+        total_fuel_cost + (median - curr_pos).abs()
+    })
+}
+
+// Similarly to the above, differentiating shows the optimal solution lies at
+// the arithmetic mean + some amount in the range -0.5..0.5. As we're dealing
+// with integers we can use the fractional part of the mean to tell if we need
+// to check (mean-1)..(mean) or (mean)..(mean+1).
+pub fn part2(crabs: &[i32]) -> i32 {
+    let mean = crabs.iter().sum::<i32>() as f32 / crabs.len() as f32;
+    let offset = (mean.fract() < 0.5) as i32;
+    let start = mean as i32 - offset;
+
+    ((start)..=(start + 1))
+        .map(|end_pos| {
+            crabs.iter().fold(0, |total_fuel_cost, curr_pos| {
+                total_fuel_cost + ((end_pos - curr_pos).pow(2) + (end_pos - curr_pos).abs()) / 2
+            })
+        })
+        .min()
+        .unwrap()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 7;
+    const NAME: &'static str = "The Treachery of Whales";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
@@ -0,0 +1,145 @@
+use aoc_common::{ParseError, Solver, Span};
+
+pub struct Panel {
+    signals: [u8; 10],
+    displays: [u8; 4],
+}
+
+fn process_block(block: Span) -> Result<u8, ParseError> {
+    block
+        .chars()
+        .try_fold(0, |acc, (curr, span)| match curr {
+            'a'..='g' => Ok(acc | (1 << (curr as u32 - 'a' as u32))),
+            _ => Err(span.error("a segment between `a` and `g`")),
+        })
+}
+
+fn process_blocks<const N: usize>(blocks: Span) -> Result<[u8; N], ParseError> {
+    let mut processed = [0; N];
+    let mut count = 0;
+    for block in blocks.split(" ") {
+        if count == N {
+            return Err(block.error(format!("only {} patterns", N)));
+        }
+        processed[count] = process_block(block)?;
+        count += 1;
+    }
+    if count < N {
+        return Err(blocks.end().error(format!("{} patterns", N)));
+    }
+    Ok(processed)
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<Vec<Panel>, ParseError> {
+    Span::new(input_str)
+        .lines()
+        .map(|line| {
+            let (lhs, rhs) = line.split_once(" | ")?;
+
+            let mut signals: [u8; 10] = process_blocks(lhs)?;
+            signals.sort_by_key(|a| a.count_ones());
+
+            let displays: [u8; 4] = process_blocks(rhs)?;
+
+            Ok(Panel { signals, displays })
+        })
+        .collect()
+}
+
+// Count number of 1s, 4s, 7s & 8s that appear in the output.
+pub fn part1(panels: &[Panel]) -> u32 {
+    panels.iter().fold(0, |acc, curr| {
+        acc + curr.displays.iter().fold(0, |acc, curr| {
+            let num_segments = curr.count_ones();
+            acc + (
+                num_segments == 2 ||
+                num_segments == 3 ||
+                num_segments == 4 ||
+                num_segments == 7   
+            ) as u32
+        })
+    })
+}
+
+// Total of all values on the displays
+pub fn part2(panels: &[Panel]) -> u32 {
+    panels.iter().fold(0, |acc, panel| {
+        let mut digits = [0; 10];
+
+        // Known digits.
+        digits[1] = panel.signals[0];
+        digits[7] = panel.signals[1];
+        digits[4] = panel.signals[2];
+        digits[8] = panel.signals[9];
+
+        // Numbers with 5 segments
+        let bd = digits[4] - digits[1];
+        for i in 3..=5 {
+            match panel.signals[i] {
+                signal if (signal & bd) == bd => digits[5] = signal,
+                signal if (signal & digits[1]) == digits[1] => digits[3] = signal,
+                signal => digits[2] = signal,
+            };
+        };
+
+        // Numbers with 6 segments
+        for i in 6..=8 {
+            match panel.signals[i] {
+                signal if (signal & digits[3]) == digits[3] => digits[9] = signal,
+                signal if (signal & digits[1]) == digits[1] => digits[0] = signal,
+                signal => digits[6] = signal,
+            }
+        }
+
+        let display_num = panel.displays.iter().enumerate()
+            .fold(0, |acc, (i, num)| {
+                let power = 10_u32.pow(3 - i as u32);
+                let digit = digits.iter().position(|digit| digit == num).unwrap();
+                acc + power * digit as u32
+            });
+
+        acc + display_num
+    })
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 8;
+    const NAME: &'static str = "Seven Segment Search";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Vec<Panel>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
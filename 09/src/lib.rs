@@ -0,0 +1,99 @@
+use aoc_common::{grid::Position, Grid, ParseError, Solver, Span};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::from_digits(Span::new(input_str))
+}
+
+pub fn get_basin_minima(terrain: &Grid<u32>) -> Vec<Position> {
+    terrain
+        .positions()
+        .filter(|&position| {
+            let height = terrain[position];
+            terrain
+                .neighbours4(position)
+                .all(|neighbour| height < terrain[neighbour])
+        })
+        .collect()
+}
+
+pub fn part1(terrain: &Grid<u32>) -> u32 {
+    get_basin_minima(terrain)
+        .iter()
+        .map(|&position| terrain[position] + 1)
+        .sum()
+}
+
+pub fn part2(terrain: &Grid<u32>) -> u32 {
+    let basin_minima = get_basin_minima(terrain);
+    let mut terrain = terrain.clone();
+    let mut floodfill_stack = Vec::new();
+    let mut basin_sizes = Vec::new();
+
+    for position in basin_minima {
+        if terrain[position] == 9 {
+            continue;
+        }
+
+        floodfill_stack.push(position);
+        terrain[position] = 9;
+
+        let mut basin_size = 1;
+
+        while let Some(position) = floodfill_stack.pop() {
+            for neighbour in terrain.neighbours4(position) {
+                if terrain[neighbour] < 9 {
+                    floodfill_stack.push(neighbour);
+                    terrain[neighbour] = 9;
+                    basin_size += 1;
+                }
+            }
+        }
+
+        basin_sizes.push(basin_size);
+    }
+
+    basin_sizes.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    basin_sizes[0..3].iter().product()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 9;
+    const NAME: &'static str = "Smoke Basin";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Grid<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
@@ -0,0 +1,115 @@
+use aoc_common::{ParseError, Solver, Span};
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(data: &str) -> Result<Vec<String>, ParseError> {
+    Span::new(data)
+        .lines()
+        .map(|line| {
+            match line.chars().find(|(char, _)| !"()[]{}<>".contains(*char)) {
+                Some((_, span)) => Err(span.error("a bracket")),
+                None => Ok(line.as_str().to_owned()),
+            }
+        })
+        .collect()
+}
+
+pub fn score_lines(lines: &[String]) -> (u64, u64) {
+    let mut error_score = 0;
+    let mut autocomplete_scores = Vec::new();
+    let mut brace_stack = Vec::new();
+
+    for line in lines {
+        let start_error = error_score;
+
+        for char in line.chars() {
+            match char {
+                '(' => brace_stack.push(')'),
+                '[' => brace_stack.push(']'),
+                '{' => brace_stack.push('}'),
+                '<' => brace_stack.push('>'),
+                closing_brace => {
+                    if Some(closing_brace) != brace_stack.pop() {
+                        error_score += match closing_brace {
+                            ')' => 3,
+                            ']' => 57,
+                            '}' => 1197,
+                            '>' => 25137,
+                            _ => unreachable!(),
+                        };
+                        break;
+                    }
+                }
+            };
+        }
+
+        if error_score == start_error {
+            let autocomplete_score = brace_stack.iter().rev().fold(0, |acc, char| {
+                acc * 5
+                    + match char {
+                        ')' => 1,
+                        ']' => 2,
+                        '}' => 3,
+                        '>' => 4,
+                        _ => unreachable!(),
+                    }
+            });
+            autocomplete_scores.push(autocomplete_score);
+        }
+
+        brace_stack.clear();
+    }
+
+    autocomplete_scores.sort();
+    let autocomplete_score = autocomplete_scores[autocomplete_scores.len() / 2];
+
+    (error_score, autocomplete_score)
+}
+
+pub fn part1(lines: &[String]) -> u64 {
+    score_lines(lines).0
+}
+
+pub fn part2(lines: &[String]) -> u64 {
+    score_lines(lines).1
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 10;
+    const NAME: &'static str = "Syntax Scoring";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
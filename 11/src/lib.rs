@@ -0,0 +1,112 @@
+use std::collections::VecDeque;
+
+use aoc_common::{grid::Position, Grid, ParseError, Solver, Span};
+
+#[derive(Clone)]
+pub struct OctoGrid {
+    cells: Grid<u8>,
+    flash_queue: VecDeque<Position>,
+}
+
+impl OctoGrid {
+    pub fn new(cells: Grid<u8>) -> Self {
+        let flash_queue = VecDeque::new();
+        Self { cells, flash_queue }
+    }
+
+    pub fn step(&mut self) -> u32 {
+        // Phase 1 - Charge
+        for position in self.cells.positions() {
+            let energy = self.cells[position];
+            if energy == 9 {
+                self.flash_queue.push_back(position);
+            }
+            self.cells[position] = (energy + 1) % 10;
+        }
+
+        // Phase 2 - Flash
+        let mut num_flashes = self.flash_queue.len() as u32;
+        while let Some(center) = self.flash_queue.pop_front() {
+            for position in self.cells.neighbours8(center) {
+                let energy = self.cells[position];
+                if energy == 9 {
+                    num_flashes += 1;
+                    self.flash_queue.push_back(position);
+                    self.cells[position] = 0;
+                }
+                else if energy > 0 {
+                    self.cells[position] = energy + 1;
+                }
+            }
+        }
+
+        num_flashes
+    }
+
+    pub fn all_flashed(&self) -> bool {
+        self.cells.cells().iter().all(|&energy| energy == 0)
+    }
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<OctoGrid, ParseError> {
+    let cells = Grid::from_digits(Span::new(input_str))?;
+
+    Ok(OctoGrid::new(cells.map(|&cell| cell as u8)))
+}
+
+pub fn part1(octos: &OctoGrid) -> u32 {
+    let mut octos = octos.clone();
+    (0..100).fold(0, |acc, _| acc + octos.step())
+}
+
+pub fn part2(octos: &OctoGrid) -> u32 {
+    let mut octos = octos.clone();
+    let mut iterations = 0;
+    while !octos.all_flashed() {
+        octos.step();
+        iterations += 1;
+    }
+    iterations
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 11;
+    const NAME: &'static str = "Dumbo Octopus";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = OctoGrid;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use aoc_common::{ParseError, Solver, Span};
+
+#[derive(PartialEq)]
+pub enum CaveType {
+    Start,
+    End,
+    Small,
+    Large,
+}
+
+pub struct Cave {
+    cave_type: CaveType,
+    connects_to: Vec<usize>,
+}
+
+impl Cave {
+    fn from_str(tag: &str) -> Self {
+        let cave_type = match tag {
+            "start" => CaveType::Start,
+            "end" => CaveType::End,
+            tag if tag == tag.to_lowercase() => CaveType::Small,
+            _ => CaveType::Large,
+        };
+
+        Self {
+            cave_type,
+            connects_to: Vec::new(),
+        }
+    }
+}
+
+pub struct CaveSystem {
+    caves: Vec<Cave>,
+    start: usize,
+}
+
+impl CaveSystem {
+    pub fn count_paths(&self, visit_small_twice: bool) -> u32 {
+        let mut visits = vec![0; self.caves.len()];
+        self.visit_cave(&mut visits, self.start, !visit_small_twice)
+    }
+
+    fn visit_cave(&self, visits: &mut Vec<u32>, cave_index: usize, visited_twice: bool) -> u32 {
+        visits[cave_index] += 1;
+        let num_paths = self.caves[cave_index]
+            .connects_to
+            .iter()
+            .fold(0, |acc, &index| {
+                acc + match self.caves[index].cave_type {
+                    CaveType::Small if visits[index] < 1 || !visited_twice => {
+                        self.visit_cave(visits, index, visits[index] >= 1 || visited_twice)
+                    }
+                    CaveType::Large => self.visit_cave(visits, index, visited_twice),
+                    CaveType::End => 1,
+                    _ => 0,
+                }
+            });
+
+        visits[cave_index] -= 1;
+        num_paths
+    }
+}
+
+fn get_cave_index(
+    caves: &mut Vec<Cave>,
+    cave_map: &mut HashMap<String, usize>,
+    tag: &str,
+) -> usize {
+    match cave_map.get(tag) {
+        Some(&index) => index,
+        None => {
+            let cave = Cave::from_str(tag);
+            let index = caves.len();
+            caves.push(cave);
+            cave_map.insert(tag.to_owned(), index);
+            index
+        }
+    }
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+fn parse_tag<'a>(tag: Span<'a>) -> Result<&'a str, ParseError> {
+    match tag.as_str() {
+        "" => Err(tag.error("a cave name")),
+        tag => Ok(tag),
+    }
+}
+
+pub fn parse(input_str: &str) -> Result<CaveSystem, ParseError> {
+    let input = Span::new(input_str);
+    let mut cave_map = HashMap::new();
+    let mut caves = Vec::new();
+
+    for line in input.lines() {
+        let (from_tag, to_tag) = line.split_once("-")?;
+
+        let from_index = get_cave_index(&mut caves, &mut cave_map, parse_tag(from_tag)?);
+        let to_index = get_cave_index(&mut caves, &mut cave_map, parse_tag(to_tag)?);
+
+        caves[from_index].connects_to.push(to_index);
+        caves[to_index].connects_to.push(from_index);
+    }
+
+    let start = caves
+        .iter()
+        .position(|cave| cave.cave_type == CaveType::Start)
+        .ok_or_else(|| input.end().error("a connection to the `start` cave"))?;
+
+    Ok(CaveSystem { caves, start })
+}
+
+pub fn part1(cave_system: &CaveSystem) -> u32 {
+    cave_system.count_paths(false)
+}
+
+pub fn part2(cave_system: &CaveSystem) -> u32 {
+    cave_system.count_paths(true)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 12;
+    const NAME: &'static str = "Passage Pathing";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = CaveSystem;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::Display;
+
+use aoc_common::{grid::Position, Answer, Grid, ParseError, Solver, Span};
+
+#[derive(Clone)]
+pub struct Sheet {
+    dots: Grid<bool>,
+}
+
+impl Sheet {
+    pub fn from_points(width: usize, height: usize, points: Vec<Position>) -> Self {
+        let mut dots = Grid::filled(width, height, false);
+
+        points.iter().for_each(|&point| dots[point] = true);
+
+        Self { dots }
+    }
+
+    pub fn fold_at(&mut self, fold: &Fold) {
+        let (width, height) = (self.dots.width(), self.dots.height());
+        match *fold {
+            Fold::X(fold_index) => {
+                for i in 0..(width - fold_index - 1) {
+                    for y in 0..height {
+                        if self.dots[(fold_index + i + 1, y)] {
+                            self.dots[(fold_index - i - 1, y)] = true;
+                        }
+                    }
+                }
+                self.dots = self.dots.crop(fold_index, height);
+            }
+            Fold::Y(fold_index) => {
+                for x in 0..width {
+                    for i in 0..(height - fold_index - 1) {
+                        if self.dots[(x, fold_index + i + 1)] {
+                            self.dots[(x, fold_index - i - 1)] = true;
+                        }
+                    }
+                }
+                self.dots = self.dots.crop(width, fold_index);
+            }
+        }
+    }
+
+    pub fn num_dots(&self) -> u32 {
+        self.dots.cells().iter().filter(|&&is_dot| is_dot).count() as u32
+    }
+}
+
+impl Display for Sheet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rendered = self.dots.map(|&is_dot| if is_dot { '█' } else { ' ' });
+        write!(f, "{}", rendered)
+    }
+}
+
+impl From<Sheet> for Answer {
+    fn from(sheet: Sheet) -> Self {
+        Answer::Text(sheet.to_string())
+    }
+}
+
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<(Sheet, Vec<Fold>), ParseError> {
+    let input = Span::new(input_str);
+    let mut sections = input.sections();
+    let points_str = sections.next().ok_or_else(|| input.error("a list of dots"))?;
+    let folds_str = sections
+        .next()
+        .ok_or_else(|| input.end().error("a list of fold instructions"))?;
+
+    let mut width = 0;
+    let mut height = 0;
+
+    let points: Vec<Position> = points_str
+        .lines()
+        .map(|line| {
+            let (x_str, y_str) = line.split_once(",")?;
+            let x = x_str.parse::<usize>("an x coordinate")?;
+            let y = y_str.parse::<usize>("a y coordinate")?;
+            width = width.max(x + 1);
+            height = height.max(y + 1);
+            Ok((x, y))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let sheet = Sheet::from_points(width, height, points);
+
+    let folds: Vec<Fold> = folds_str
+        .lines()
+        .map(|line| {
+            let fold_str = line.strip_prefix("fold along ")?;
+            let (axis_str, index_str) = fold_str.split_once("=")?;
+            let index = index_str.parse::<usize>("a fold position")?;
+            let (fold, size) = match axis_str.as_str() {
+                "x" => (Fold::X(index), &mut width),
+                "y" => (Fold::Y(index), &mut height),
+                _ => return Err(axis_str.error("`x` or `y`")),
+            };
+
+            // The fold has to be inside the sheet, and no nearer the start
+            // than the end, so that every dot lands back on the sheet.
+            if *size <= 1 {
+                return Err(axis_str.error("a fold along the other axis"));
+            }
+            let first = (*size / 2).max(1);
+            if index < first || index >= *size {
+                let expected = format!("a fold position from {} to {}", first, *size - 1);
+                return Err(index_str.error(expected));
+            }
+            *size = index;
+            Ok(fold)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((sheet, folds))
+}
+
+pub fn part1((sheet, folds): &(Sheet, Vec<Fold>)) -> u32 {
+    let mut sheet = sheet.clone();
+    sheet.fold_at(&folds[0]);
+    sheet.num_dots()
+}
+
+pub fn part2((sheet, folds): &(Sheet, Vec<Fold>)) -> Sheet {
+    let mut sheet = sheet.clone();
+    folds.iter().for_each(|fold| sheet.fold_at(fold));
+    sheet
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 13;
+    const NAME: &'static str = "Transparent Origami";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = (Sheet, Vec<Fold>);
+    type Part1 = u32;
+    type Part2 = Sheet;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::{parse, Puzzle};
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+
+    #[test]
+    fn test_invalid_folds() {
+        let error = parse("0,0\n9,0\n\nfold along x=12\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 14: expected a fold position from 5 to 9, found `12`"
+        );
+
+        // Each fold leaves less of the sheet for the next one.
+        let input = "0,0\n9,4\n\nfold along x=5\nfold along y=2\nfold along x=1\n";
+        let error = parse(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 6, column 14: expected a fold position from 2 to 4, found `1`"
+        );
+
+        let error = parse("0,0\n0,4\n\nfold along x=0\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 12: expected a fold along the other axis, found `x`"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use aoc_common::{ParseError, Solver, Span};
+
+/// Pair insertion rules, mapping a pair of elements to the element inserted
+/// between them.
+pub type Rules = HashMap<(char, char), char>;
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+fn parse_chars<const N: usize>(span: Span, expected: &str) -> Result<[char; N], ParseError> {
+    let chars: Vec<char> = span.as_str().chars().collect();
+    chars.try_into().map_err(|_| span.error(expected))
+}
+
+pub fn parse(input_str: &str) -> Result<(Vec<char>, Rules), ParseError> {
+    let input = Span::new(input_str);
+    let mut sections = input.sections();
+    let template_str = sections.next().ok_or_else(|| input.error("a polymer template"))?;
+    let rules_str = sections
+        .next()
+        .ok_or_else(|| input.end().error("a list of insertion rules"))?;
+
+    let template: Vec<char> = template_str.as_str().chars().collect();
+
+    let mut rules = HashMap::new();
+    for line in rules_str.lines() {
+        let (matcher_str, insert_str) = line.split_once(" -> ")?;
+        let [match_0, match_1] = parse_chars(matcher_str, "a pair of elements")?;
+        let [insert] = parse_chars(insert_str, "a single element")?;
+        rules.insert((match_0, match_1), insert);
+    }
+
+    Ok((template, rules))
+}
+
+pub fn run_iterations(iterations: u32, template: &[char], rules: &Rules) -> u64 {
+    let mut single_counts = HashMap::new();
+    let mut pair_counts = HashMap::new();
+    let mut pair_counts_prev = HashMap::new();
+
+    single_counts.insert(template[0], 1);
+    for i in 0..template.len() - 1 {
+        *single_counts.entry(template[i + 1]).or_insert(0) += 1;
+        let pair = (template[i], template[i + 1]);
+        *pair_counts.entry(pair).or_insert(0) += 1;
+    }
+
+    for _ in 0..iterations {
+        std::mem::swap(&mut pair_counts, &mut pair_counts_prev);
+        pair_counts.clear();
+
+        for (prev_pair, count) in &pair_counts_prev {
+            let &new_char = rules.get(prev_pair).unwrap();
+            *single_counts.entry(new_char).or_insert(0) += count;
+
+            let pair_left = (prev_pair.0, new_char);
+            let pair_right = (new_char, prev_pair.1);
+
+            *pair_counts.entry(pair_left).or_insert(0) += count;
+            *pair_counts.entry(pair_right).or_insert(0) += count;
+        }
+    }
+
+    let mut min_count = u64::MAX;
+    let mut max_count = u64::MIN;
+    for &count in single_counts.values() {
+        min_count = min_count.min(count);
+        max_count = max_count.max(count);
+    }
+
+    max_count - min_count
+}
+
+pub fn part1((template, rules): &(Vec<char>, Rules)) -> u64 {
+    run_iterations(10, template, rules)
+}
+
+pub fn part2((template, rules): &(Vec<char>, Rules)) -> u64 {
+    run_iterations(40, template, rules)
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 14;
+    const NAME: &'static str = "Extended Polymerization";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = (Vec<char>, Rules);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
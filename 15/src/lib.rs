@@ -0,0 +1,142 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use aoc_common::{grid::Position, Grid, ParseError, Solver, Span};
+
+#[derive(Clone)]
+pub struct Cavern {
+    risks: Grid<u32>,
+}
+
+impl Cavern {
+    fn dist_manhattan(start: Position, end: Position) -> u32 {
+        (start.0.abs_diff(end.0) + start.1.abs_diff(end.1)) as u32
+    }
+
+    pub fn shortest_path(&self) -> u32 {
+        let start = (0, 0);
+        let end = (self.risks.width() - 1, self.risks.height() - 1);
+
+        let mut costs = Grid::filled(self.risks.width(), self.risks.height(), u32::MAX);
+        costs[start] = 0;
+
+        // Nodes hold the index of a cell rather than its position, since the
+        // smaller the nodes are the faster the heap can reorder them.
+        let mut heap = BinaryHeap::new();
+        heap.push(SearchNode {
+            index: 0,
+            priority: 0,
+        });
+
+        while let Some(SearchNode { index, priority: _ }) = heap.pop() {
+            let position = self.risks.position_of(index);
+            let cost = costs[position];
+            if position == end {
+                return cost;
+            }
+
+            for adjacent in self.risks.neighbours4(position) {
+                let next_cost = cost + self.risks[adjacent];
+                if next_cost < costs[adjacent] {
+                    costs[adjacent] = next_cost;
+
+                    let priority = next_cost + Self::dist_manhattan(adjacent, end);
+                    heap.push(SearchNode {
+                        index: self.risks.index_of(adjacent).unwrap(),
+                        priority,
+                    });
+                }
+            }
+        }
+
+        unreachable!()
+    }
+
+    pub fn expand_times(&mut self, scale_x: usize, scale_y: usize) {
+        let width = self.risks.width();
+        let height = self.risks.height();
+
+        self.risks = Grid::from_fn(width * scale_x, height * scale_y, |(x, y)| {
+            let risk_boost = (y / height + x / width) as u32;
+            let current_value = self.risks[(x % width, y % height)];
+            (current_value + risk_boost - 1) % 9 + 1
+        });
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct SearchNode {
+    index: usize,
+    priority: u32,
+}
+
+impl Ord for SearchNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.index.cmp(&other.index))
+    }
+}
+
+impl PartialOrd for SearchNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<Cavern, ParseError> {
+    let risks = Grid::from_digits(Span::new(input_str))?;
+    Ok(Cavern { risks })
+}
+
+pub fn part1(cavern: &Cavern) -> u32 {
+    cavern.shortest_path()
+}
+
+pub fn part2(cavern: &Cavern) -> u32 {
+    let mut cavern = cavern.clone();
+    cavern.expand_times(5, 5);
+    cavern.shortest_path()
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 15;
+    const NAME: &'static str = "Chiton";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Cavern;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::Puzzle;
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+}
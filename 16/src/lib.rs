@@ -0,0 +1,466 @@
+use aoc_common::{ParseError, Solver, Span};
+
+#[derive(Debug)]
+pub struct Header {
+    version: u8,
+    packet_type: u8,
+}
+
+#[derive(Debug)]
+pub struct Literal {
+    header: Header,
+    value: u64,
+}
+
+#[derive(Debug)]
+pub struct Operator {
+    header: Header,
+    packets: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub enum Packet {
+    Literal(Literal),
+    Operator(Operator),
+}
+
+#[derive(Debug)]
+pub struct Transmission {
+    nodes: Vec<Packet>,
+    root_node: usize,
+}
+
+impl Transmission {
+    pub fn sum_version_numbers(&self) -> u32 {
+        let packet = &self.nodes[self.root_node];
+        self.sum_versions(packet)
+    }
+
+    fn sum_versions(&self, packet: &Packet) -> u32 {
+        match packet {
+            Packet::Literal(literal) => literal.header.version as u32,
+            Packet::Operator(operator) => operator
+                .packets
+                .iter()
+                .map(|&index| self.sum_versions(&self.nodes[index]))
+                .fold(operator.header.version as u32, |accum, curr| accum + curr),
+        }
+    }
+
+    pub fn calc_expression(&self) -> u64 {
+        let packet = &self.nodes[self.root_node];
+        self.calc_subexpression(packet)
+    }
+
+    fn calc_subexpression(&self, packet: &Packet) -> u64 {
+        match packet {
+            Packet::Literal(literal) => literal.value,
+            Packet::Operator(operator) => {
+                let mut iter = operator
+                    .packets
+                    .iter()
+                    .map(|&index| self.calc_subexpression(&self.nodes[index]));
+
+                match operator.header.packet_type {
+                    0 => iter.sum(),
+                    1 => iter.product(),
+                    2 => iter.min().unwrap_or(0),
+                    3 => iter.max().unwrap_or(0),
+                    5 => {
+                        let first = iter.next().unwrap();
+                        let second = iter.next().unwrap();
+                        (first > second) as u64
+                    }
+                    6 => {
+                        let first = iter.next().unwrap();
+                        let second = iter.next().unwrap();
+                        (first < second) as u64
+                    }
+                    7 => {
+                        let first = iter.next().unwrap();
+                        let second = iter.next().unwrap();
+                        (first == second) as u64
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+pub struct TransmissionParser<'a> {
+    input: Span<'a>,
+    bytes: Vec<u8>,
+    bit_pos: usize,
+    nodes: Vec<Packet>,
+    root_node: usize,
+}
+
+impl<'a> TransmissionParser<'a> {
+    /// Reads the hexadecimal digits of a transmission, ready to be parsed.
+    pub fn new(input_str: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            input: Span::new(input_str).trim_end(),
+            bytes: process_input(input_str)?,
+            bit_pos: 0,
+            nodes: Vec::new(),
+            root_node: 0,
+        })
+    }
+
+    pub fn parse(mut self) -> Result<Transmission, ParseError> {
+        self.root_node = self.parse_packet()?;
+
+        Ok(Transmission {
+            nodes: self.nodes,
+            root_node: self.root_node,
+        })
+    }
+
+    /// Builds an error pointing at the hexadecimal digit holding `bit_pos`.
+    fn error_at(&self, bit_pos: usize, expected: &str) -> ParseError {
+        match self.input.chars().nth(bit_pos / 4) {
+            Some((_, digit)) => digit.error(expected),
+            None => self.input.end().error(expected),
+        }
+    }
+
+    fn parse_packet(&mut self) -> Result<usize, ParseError> {
+        let start = self.bit_pos;
+        let header = self.read_header()?;
+        let index = match header.packet_type {
+            4 => self.read_literal(header)?,
+            _ => self.read_operator(header)?,
+        };
+
+        // The comparison operators are only defined for a pair of packets.
+        if let Packet::Operator(operator) = &self.nodes[index] {
+            if (5..=7).contains(&operator.header.packet_type) && operator.packets.len() != 2 {
+                return Err(self.error_at(start, "a comparison of exactly two packets"));
+            }
+        }
+
+        Ok(index)
+    }
+
+    fn read_header(&mut self) -> Result<Header, ParseError> {
+        let version = self.read_u8(3)?;
+        let packet_type = self.read_u8(3)?;
+        Ok(Header {
+            version,
+            packet_type,
+        })
+    }
+
+    fn read_literal(&mut self, header: Header) -> Result<usize, ParseError> {
+        let start = self.bit_pos;
+        let mut value: u64 = 0;
+
+        loop {
+            let has_next = self.read_u8(1)?;
+
+            if value.leading_zeros() < 4 {
+                return Err(self.error_at(start, "a literal value that fits in 64 bits"));
+            }
+            let chunk = self.read_u8(4)? as u64;
+            value = (value << 4) | chunk;
+
+            if has_next == 0 {
+                break;
+            }
+        }
+
+        let literal = Literal { header, value };
+        let packet = Packet::Literal(literal);
+        let index = self.nodes.len();
+        self.nodes.push(packet);
+
+        Ok(index)
+    }
+
+    fn read_operator(&mut self, header: Header) -> Result<usize, ParseError> {
+        match self.read_u8(1)? {
+            0 => self.read_op_by_len(header),
+            1 => self.read_op_by_count(header),
+            _ => unreachable!(),
+        }
+    }
+
+    fn read_op_by_len(&mut self, header: Header) -> Result<usize, ParseError> {
+        let content_width = self.read_u16(15)? as usize;
+        let end_pos = self.bit_pos + content_width;
+
+        let mut operator = Operator {
+            header,
+            packets: Vec::new(),
+        };
+
+        while self.bit_pos < end_pos {
+            let child_index = self.parse_packet()?;
+            operator.packets.push(child_index);
+        }
+
+        if self.bit_pos != end_pos {
+            let expected = format!("sub-packets that take up exactly {} bits", content_width);
+            return Err(self.error_at(end_pos, &expected));
+        }
+
+        let packet = Packet::Operator(operator);
+        let index = self.nodes.len();
+        self.nodes.push(packet);
+
+        Ok(index)
+    }
+
+    fn read_op_by_count(&mut self, header: Header) -> Result<usize, ParseError> {
+        let num_packets = self.read_u16(11)?;
+
+        let mut operator = Operator {
+            header,
+            packets: Vec::new(),
+        };
+
+        for _ in 0..num_packets {
+            let child_index = self.parse_packet()?;
+            operator.packets.push(child_index);
+        }
+
+        let packet = Packet::Operator(operator);
+        let index = self.nodes.len();
+        self.nodes.push(packet);
+
+        Ok(index)
+    }
+
+    /// Checks that another `n` bits can be read before reaching the end of the
+    /// transmission.
+    fn check_remaining(&self, n: usize) -> Result<(), ParseError> {
+        if self.bit_pos + n > self.bytes.len() * 8 {
+            let expected = format!("{} more bits of the packet", n);
+            return Err(self.error_at(self.bytes.len() * 8, &expected));
+        }
+        Ok(())
+    }
+
+    fn read_u8(&mut self, n: usize) -> Result<u8, ParseError> {
+        self.check_remaining(n)?;
+        let mut value = 0;
+
+        for _ in 0..n {
+            let index = self.bit_pos / 8;
+            let shift = 8 - (self.bit_pos % 8) - 1;
+
+            let current_bit = (self.bytes[index] & (1 << shift)) >> shift;
+            value = (value << 1) | current_bit;
+            self.bit_pos += 1;
+        }
+
+        Ok(value)
+    }
+
+    fn read_u16(&mut self, n: usize) -> Result<u16, ParseError> {
+        self.check_remaining(n)?;
+        let mut value = 0;
+
+        for _ in 0..n {
+            let index = self.bit_pos / 8;
+            let shift = 8 - (self.bit_pos % 8) - 1;
+
+            let current_bit = ((self.bytes[index] & (1 << shift)) >> shift) as u16;
+            value = (value << 1) | current_bit;
+            self.bit_pos += 1;
+        }
+
+        Ok(value)
+    }
+}
+
+pub const INPUT: &str = include_str!("input.txt");
+pub const EXAMPLE: &str = include_str!("example.txt");
+
+pub fn parse(input_str: &str) -> Result<Transmission, ParseError> {
+    TransmissionParser::new(input_str)?.parse()
+}
+
+pub fn part1(transmission: &Transmission) -> u32 {
+    transmission.sum_version_numbers()
+}
+
+pub fn part2(transmission: &Transmission) -> u64 {
+    transmission.calc_expression()
+}
+
+pub fn process_input(input_str: &str) -> Result<Vec<u8>, ParseError> {
+    let input = Span::new(input_str).trim_end();
+    let digits = input
+        .chars()
+        .map(|(char, span)| char.to_digit(16).ok_or_else(|| span.error("a hexadecimal digit")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if digits.len() % 2 != 0 {
+        return Err(input.end().error("an even number of hexadecimal digits"));
+    }
+
+    Ok(digits
+        .chunks(2)
+        .map(|pair| (pair[0] << 4 | pair[1]) as u8)
+        .collect())
+}
+
+pub struct Puzzle;
+
+impl Solver for Puzzle {
+    const DAY: u8 = 16;
+    const NAME: &'static str = "Packet Decoder";
+    const INPUT: &'static str = INPUT;
+    const ANSWERS: &'static str = include_str!("answers.toml");
+    const EXAMPLE: &'static str = EXAMPLE;
+    const EXAMPLE_ANSWERS: &'static str = include_str!("example_answers.toml");
+
+    type Data = Transmission;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Part1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Part2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::assert_example;
+
+    use crate::{parse, Puzzle};
+
+    #[test]
+    fn test_example() {
+        assert_example::<Puzzle>();
+    }
+
+    #[test]
+    fn test_version_numbers() {
+        let version_sum_a = parse("8A004A801A8002F478").unwrap().sum_version_numbers();
+
+        assert_eq!(version_sum_a, 16);
+
+        let version_sum_b = parse("620080001611562C8802118E34")
+            .unwrap()
+            .sum_version_numbers();
+
+        assert_eq!(version_sum_b, 12);
+
+        let version_sum_c = parse("C0015000016115A2E0802F182340")
+            .unwrap()
+            .sum_version_numbers();
+
+        assert_eq!(version_sum_c, 23);
+
+        let version_sum_d = parse("A0016C880162017C3686B18A3D4780")
+            .unwrap()
+            .sum_version_numbers();
+
+        assert_eq!(version_sum_d, 31);
+    }
+
+    #[test]
+    fn test_value_extraction() {
+        let result = parse("D2FE28").unwrap().calc_expression();
+
+        assert_eq!(result, 2021);
+    }
+
+    #[test]
+    fn test_sum() {
+        let result = parse("C200B40A82").unwrap().calc_expression();
+
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_product() {
+        let result = parse("04005AC33890").unwrap().calc_expression();
+
+        assert_eq!(result, 54);
+    }
+
+    #[test]
+    fn test_minimum() {
+        let result = parse("880086C3E88112").unwrap().calc_expression();
+
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    fn test_maximum() {
+        let result = parse("CE00C43D881120").unwrap().calc_expression();
+
+        assert_eq!(result, 9);
+    }
+
+    #[test]
+    fn test_less_than_true() {
+        let result = parse("D8005AC2A8F0").unwrap().calc_expression();
+
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_less_than() {
+        let result = parse("F600BC2D8F").unwrap().calc_expression();
+
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_greater_than() {
+        let result = parse("F600BC2D8F").unwrap().calc_expression();
+
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_equal() {
+        let result = parse("9C005AC2F8F0").unwrap().calc_expression();
+
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_hierarchy() {
+        let result = parse("9C0141080250320F1802104A08")
+            .unwrap()
+            .calc_expression();
+
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_malformed_transmissions() {
+        // The literal's first group of bits is cut off.
+        let error = parse("D2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.expected, "4 more bits of the packet");
+        assert_eq!(error.found, "end of line");
+
+        let error = parse("16004408").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "a comparison of exactly two packets");
+
+        // Two literals take up 22 bits, not the 20 the operator gives them.
+        let error = parse("000050408810").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+
+        let error = parse("D2F").unwrap_err();
+        assert_eq!(error.expected, "an even number of hexadecimal digits");
+        let error = parse("D2FG").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (4, "`G`"));
+    }
+}
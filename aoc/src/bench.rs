@@ -0,0 +1,261 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{ParseError, Solver, Span};
+
+/// Every measurement is repeated at least this many times...
+const MIN_RUNS: usize = 5;
+/// ...and at most this many, whatever the time budget allows.
+const MAX_RUNS: usize = 1000;
+
+/// Differences smaller than this are treated as timer noise and never
+/// reported as regressions, however large they are relative to the baseline.
+const MIN_REGRESSION: Duration = Duration::from_micros(2);
+
+/// A separately timed stage of solving a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Step {
+    pub const ALL: [Step; 3] = [Step::Parse, Step::Part1, Step::Part2];
+
+    /// The name used for the step in baseline files.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Part1 => "part1",
+            Self::Part2 => "part2",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|step| step.key() == key)
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "Parse"),
+            Self::Part1 => write!(f, "Part 1"),
+            Self::Part2 => write!(f, "Part 2"),
+        }
+    }
+}
+
+/// The median time taken by one step of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sample {
+    pub day: u8,
+    pub step: Step,
+    pub median: Duration,
+}
+
+/// Times parsing and both parts of a day separately, running each one
+/// repeatedly until `budget` is used up and keeping the median.
+pub fn measure<S: Solver>(input: &str, budget: Duration) -> Result<Vec<Sample>, ParseError> {
+    let data = S::parse(input)?;
+    let sample = |step, median| Sample {
+        day: S::DAY,
+        step,
+        median,
+    };
+
+    Ok(vec![
+        sample(
+            Step::Parse,
+            median_time(budget, || S::parse(black_box(input))),
+        ),
+        sample(
+            Step::Part1,
+            median_time(budget, || S::part1(black_box(&data))),
+        ),
+        sample(
+            Step::Part2,
+            median_time(budget, || S::part2(black_box(&data))),
+        ),
+    ])
+}
+
+fn median_time<T>(budget: Duration, mut f: impl FnMut() -> T) -> Duration {
+    // One untimed run first so that caches and allocations are warmed up.
+    black_box(f());
+
+    let start = Instant::now();
+    let mut times = Vec::new();
+    while times.len() < MAX_RUNS && (times.len() < MIN_RUNS || start.elapsed() < budget) {
+        let run_start = Instant::now();
+        black_box(f());
+        times.push(run_start.elapsed());
+    }
+
+    times.sort_unstable();
+    times[times.len() / 2]
+}
+
+/// Previously recorded timings, stored as CSV with one `day,step,median_ns`
+/// row per step so that they are easy to process with other tools.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    times: BTreeMap<(u8, Step), Duration>,
+}
+
+const HEADER: &str = "day,step,median_ns";
+
+impl Baseline {
+    pub fn from_samples(samples: &[Sample]) -> Self {
+        let times = samples
+            .iter()
+            .map(|sample| ((sample.day, sample.step), sample.median))
+            .collect();
+        Self { times }
+    }
+
+    pub fn parse(csv: &str) -> Result<Self, ParseError> {
+        let mut lines = Span::new(csv).lines();
+        match lines.next() {
+            Some(header) if header.as_str() == HEADER => (),
+            Some(header) => return Err(header.error(format!("the header `{}`", HEADER))),
+            None => return Err(Span::new(csv).error(format!("the header `{}`", HEADER))),
+        }
+
+        let mut times = BTreeMap::new();
+        for line in lines {
+            let mut fields = line.split(",");
+            let mut field = |expected| {
+                fields
+                    .next()
+                    .filter(|field| !field.is_empty())
+                    .ok_or_else(|| line.end().error(expected))
+            };
+
+            let day = field("a day")?.parse("a day")?;
+            let step_field = field("a step")?;
+            let step = Step::from_key(step_field.as_str())
+                .ok_or_else(|| step_field.error("`parse`, `part1` or `part2`"))?;
+            let nanos = field("a time in nanoseconds")?.parse("a time in nanoseconds")?;
+            if let Some(extra) = fields.next() {
+                return Err(extra.error("the end of the line"));
+            }
+
+            times.insert((day, step), Duration::from_nanos(nanos));
+        }
+
+        Ok(Self { times })
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", HEADER);
+        for (&(day, step), time) in &self.times {
+            csv.push_str(&format!("{},{},{}\n", day, step.key(), time.as_nanos()));
+        }
+        csv
+    }
+
+    pub fn get(&self, day: u8, step: Step) -> Option<Duration> {
+        self.times.get(&(day, step)).copied()
+    }
+
+    /// Compares a new sample with the recorded one. A sample is a regression
+    /// when it is more than `threshold` percent slower than the baseline.
+    pub fn compare(&self, sample: &Sample, threshold: f64) -> Option<Comparison> {
+        let baseline = self.get(sample.day, sample.step)?;
+        let change = match baseline.as_secs_f64() {
+            0.0 => 0.0,
+            base => (sample.median.as_secs_f64() / base - 1.0) * 100.0,
+        };
+        let regression =
+            change > threshold && sample.median.saturating_sub(baseline) >= MIN_REGRESSION;
+
+        Some(Comparison { change, regression })
+    }
+}
+
+/// How a sample differs from the baseline, as a percentage of the baseline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comparison {
+    pub change: f64,
+    pub regression: bool,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:+.1}%", self.change)?;
+        if self.regression {
+            write!(f, " REGRESSION")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{Baseline, Sample, Step};
+
+    fn sample(day: u8, step: Step, micros: u64) -> Sample {
+        Sample {
+            day,
+            step,
+            median: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline::from_samples(&[
+            sample(2, Step::Part1, 30),
+            sample(1, Step::Parse, 52),
+            sample(1, Step::Part2, 4),
+        ]);
+        let csv = baseline.to_csv();
+        assert_eq!(
+            csv,
+            "day,step,median_ns\n1,parse,52000\n1,part2,4000\n2,part1,30000\n"
+        );
+        assert_eq!(Baseline::parse(&csv).unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_baseline_errors() {
+        let error = Baseline::parse("day,step,median\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+
+        let error = Baseline::parse("day,step,median_ns\n1,part3,52000\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = Baseline::parse("day,step,median_ns\n1,parse\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 8));
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline =
+            Baseline::from_samples(&[sample(1, Step::Parse, 100), sample(1, Step::Part1, 1)]);
+
+        let comparison = baseline
+            .compare(&sample(1, Step::Parse, 150), 10.0)
+            .unwrap();
+        assert!(comparison.regression);
+        assert_eq!(comparison.to_string(), "+50.0% REGRESSION");
+
+        let comparison = baseline.compare(&sample(1, Step::Parse, 95), 10.0).unwrap();
+        assert!(!comparison.regression);
+        assert_eq!(comparison.to_string(), "-5.0%");
+
+        // Tiny steps can double in time from noise alone.
+        let comparison = baseline.compare(&sample(1, Step::Part1, 2), 10.0).unwrap();
+        assert!(!comparison.regression);
+
+        assert_eq!(baseline.compare(&sample(1, Step::Part2, 2), 10.0), None);
+    }
+}
@@ -0,0 +1,283 @@
+use aoc_common::{report::Format, Part};
+
+use crate::days::DAYS;
+
+pub const USAGE: &str = "\
+Usage: aoc run <day>... [--part N] [--input PATH | --example] [--format text|json]
+       aoc verify [<day>...] [--example]
+       aoc bench [<day>...] [--baseline PATH] [--save PATH] [--threshold PCT]
+
+Days can be given as numbers, as ranges such as 3-7, or as `all`.
+--input may only be used with a single day, and `-` reads from stdin.
+--example uses the worked example from each day's puzzle description.
+verify checks every given day, or all of them, against its answers.toml,
+or against example_answers.toml with --example.
+bench times parsing and each part of every given day, or all of them.
+--save writes the timings to a CSV baseline, and --baseline compares them
+with an earlier one, flagging steps more than --threshold percent slower
+(25 by default).";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    pub days: Vec<u8>,
+    pub parts: Vec<Part>,
+    pub input: Option<String>,
+    pub example: bool,
+    pub format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyArgs {
+    pub days: Vec<u8>,
+    pub example: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub days: Vec<u8>,
+    pub baseline: Option<String>,
+    pub save: Option<String>,
+    pub threshold: f64,
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    let (command, rest) = args.split_first().ok_or("missing command")?;
+    match command.as_str() {
+        "run" => parse_run(rest).map(Command::Run),
+        "verify" => parse_verify(rest).map(Command::Verify),
+        "bench" => parse_bench(rest).map(Command::Bench),
+        other => Err(format!("unknown command `{}`", other)),
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<RunArgs, String> {
+    let mut days = Vec::new();
+    let mut parts = Part::BOTH.to_vec();
+    let mut input = None;
+    let mut example = false;
+    let mut format = Format::Text;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = iter.next().ok_or("--part requires a value")?;
+                let part = value
+                    .parse()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| format!("invalid part `{}`", value))?;
+                parts = vec![part];
+            }
+            "--input" => {
+                let value = iter.next().ok_or("--input requires a path")?;
+                input = Some(value.clone());
+            }
+            "--example" => example = true,
+            "--format" => {
+                let value = iter.next().ok_or("--format requires a value")?;
+                format = value.parse()?;
+            }
+            day => days.extend(parse_days(day)?),
+        }
+    }
+
+    if days.is_empty() {
+        return Err("no days given".to_owned());
+    }
+    if input.is_some() && days.len() > 1 {
+        return Err("--input can only be used with a single day".to_owned());
+    }
+    if input.is_some() && example {
+        return Err("--input and --example cannot be used together".to_owned());
+    }
+
+    Ok(RunArgs {
+        days,
+        parts,
+        input,
+        example,
+        format,
+    })
+}
+
+fn parse_verify(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut days = Vec::new();
+    let mut example = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "--example" => example = true,
+            day => days.extend(parse_days(day)?),
+        }
+    }
+
+    if days.is_empty() {
+        days = parse_days("all")?;
+    }
+    Ok(VerifyArgs { days, example })
+}
+
+fn parse_bench(args: &[String]) -> Result<BenchArgs, String> {
+    let mut days = Vec::new();
+    let mut baseline = None;
+    let mut save = None;
+    let mut threshold = 25.0;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--baseline" => {
+                let value = iter.next().ok_or("--baseline requires a path")?;
+                baseline = Some(value.clone());
+            }
+            "--save" => {
+                let value = iter.next().ok_or("--save requires a path")?;
+                save = Some(value.clone());
+            }
+            "--threshold" => {
+                let value = iter.next().ok_or("--threshold requires a value")?;
+                threshold = value
+                    .parse()
+                    .ok()
+                    .filter(|threshold: &f64| *threshold >= 0.0)
+                    .ok_or_else(|| format!("invalid threshold `{}`", value))?;
+            }
+            day => days.extend(parse_days(day)?),
+        }
+    }
+
+    if days.is_empty() {
+        days = parse_days("all")?;
+    }
+    Ok(BenchArgs {
+        days,
+        baseline,
+        save,
+        threshold,
+    })
+}
+
+fn parse_days(arg: &str) -> Result<Vec<u8>, String> {
+    if arg == "all" {
+        return Ok(DAYS.iter().map(|day| day.number).collect());
+    }
+
+    let parse_day = |day: &str| {
+        day.parse::<u8>()
+            .ok()
+            .filter(|&day| DAYS.iter().any(|registered| registered.number == day))
+            .ok_or_else(|| format!("unknown day `{}`", day))
+    };
+
+    match arg.split_once('-') {
+        Some((first, last)) => Ok((parse_day(first)?..=parse_day(last)?).collect()),
+        None => Ok(vec![parse_day(arg)?]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{report::Format, Part};
+
+    use crate::cli::{parse_args, BenchArgs, Command, RunArgs, VerifyArgs};
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_run_single_day() {
+        let command = parse_args(&args("run 3 --part 2 --input input.txt --format json")).unwrap();
+        assert_eq!(
+            command,
+            Command::Run(RunArgs {
+                days: vec![3],
+                parts: vec![Part::Two],
+                input: Some("input.txt".to_owned()),
+                example: false,
+                format: Format::Json,
+            })
+        );
+    }
+
+    #[test]
+    fn test_run_day_list() {
+        let Command::Run(run) = parse_args(&args("run 1 4-6 16")).unwrap() else {
+            panic!("expected a run command");
+        };
+        assert_eq!(run.days, vec![1, 4, 5, 6, 16]);
+        assert_eq!(run.parts, vec![Part::One, Part::Two]);
+        assert_eq!(run.format, Format::Text);
+
+        let Command::Run(run) = parse_args(&args("run all")).unwrap() else {
+            panic!("expected a run command");
+        };
+        assert_eq!(run.days.len(), 16);
+
+        let Command::Run(run) = parse_args(&args("run 4 --example")).unwrap() else {
+            panic!("expected a run command");
+        };
+        assert!(run.example);
+    }
+
+    #[test]
+    fn test_verify() {
+        let command = parse_args(&args("verify 2 9-10")).unwrap();
+        assert_eq!(
+            command,
+            Command::Verify(VerifyArgs {
+                days: vec![2, 9, 10],
+                example: false,
+            })
+        );
+
+        let Command::Verify(verify) = parse_args(&args("verify --example")).unwrap() else {
+            panic!("expected a verify command");
+        };
+        assert_eq!(verify.days.len(), 16);
+        assert!(verify.example);
+    }
+
+    #[test]
+    fn test_bench() {
+        let command = parse_args(&args("bench 15 --baseline old.csv --threshold 40")).unwrap();
+        assert_eq!(
+            command,
+            Command::Bench(BenchArgs {
+                days: vec![15],
+                baseline: Some("old.csv".to_owned()),
+                save: None,
+                threshold: 40.0,
+            })
+        );
+
+        let Command::Bench(bench) = parse_args(&args("bench --save new.csv")).unwrap() else {
+            panic!("expected a bench command");
+        };
+        assert_eq!(bench.days.len(), 16);
+        assert_eq!(bench.save.as_deref(), Some("new.csv"));
+        assert_eq!(bench.threshold, 25.0);
+
+        assert!(parse_args(&args("bench --threshold -5")).is_err());
+        assert!(parse_args(&args("bench --save")).is_err());
+    }
+
+    #[test]
+    fn test_run_errors() {
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run 17")).is_err());
+        assert!(parse_args(&args("run 1 --part 3")).is_err());
+        assert!(parse_args(&args("run 1 2 --input input.txt")).is_err());
+        assert!(parse_args(&args("run 1 --input input.txt --example")).is_err());
+        assert!(parse_args(&args("run 1 --format yaml")).is_err());
+        assert!(parse_args(&args("fly 1")).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use aoc_common::{
+    runner::{solve, PartResult, SolveError},
+    ParseError, Part, Solver,
+};
+
+use crate::bench::{measure, Sample};
+
+/// A registered day, with its `Solver` erased so that every day can be kept
+/// in the same table.
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    pub input: &'static str,
+    pub answers: &'static str,
+    pub example: &'static str,
+    pub example_answers: &'static str,
+    solve: fn(&str, &[Part]) -> Result<Vec<PartResult>, SolveError>,
+    measure: fn(&str, Duration) -> Result<Vec<Sample>, ParseError>,
+}
+
+impl Day {
+    pub const fn new<S: Solver>() -> Self {
+        Self {
+            number: S::DAY,
+            name: S::NAME,
+            input: S::INPUT,
+            answers: S::ANSWERS,
+            example: S::EXAMPLE,
+            example_answers: S::EXAMPLE_ANSWERS,
+            solve: solve::<S>,
+            measure: measure::<S>,
+        }
+    }
+
+    /// Parses `input` and runs the requested parts against it.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<PartResult>, SolveError> {
+        (self.solve)(input, parts)
+    }
+
+    /// Times parsing `input` and each part, spending roughly `budget` on each.
+    pub fn bench(&self, input: &str, budget: Duration) -> Result<Vec<Sample>, ParseError> {
+        (self.measure)(input, budget)
+    }
+}
+
+pub const DAYS: [Day; 16] = [
+    Day::new::<sonar_sweep::Puzzle>(),
+    Day::new::<dive::Puzzle>(),
+    Day::new::<binary_diagnostic::Puzzle>(),
+    Day::new::<giant_squid::Puzzle>(),
+    Day::new::<hydrothermal_venture::Puzzle>(),
+    Day::new::<lanternfish::Puzzle>(),
+    Day::new::<the_treachery_of_whales::Puzzle>(),
+    Day::new::<seven_segment_search::Puzzle>(),
+    Day::new::<smoke_basin::Puzzle>(),
+    Day::new::<syntax_scoring::Puzzle>(),
+    Day::new::<dumbo_octopus::Puzzle>(),
+    Day::new::<passage_pathing::Puzzle>(),
+    Day::new::<transparent_origami::Puzzle>(),
+    Day::new::<extended_polymerization::Puzzle>(),
+    Day::new::<chiton::Puzzle>(),
+    Day::new::<packet_decoder::Puzzle>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,7 @@
+//! Dispatcher that runs any of the registered days through their common
+//! `Solver` implementation.
+
+pub mod bench;
+pub mod cli;
+pub mod days;
+pub mod verify;
@@ -0,0 +1,151 @@
+use std::{env, fs, process, time::Duration};
+
+use aoc::{
+    bench::Baseline,
+    cli::{parse_args, BenchArgs, Command, RunArgs, VerifyArgs, USAGE},
+    days,
+    verify::verify,
+};
+use aoc_common::{
+    expected::Outcome,
+    input::Source,
+    report::{render, Format},
+};
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let source = Source::from_arg(args.input.as_deref());
+    let mut all_results = Vec::new();
+
+    for &number in &args.days {
+        let day = days::find(number).ok_or_else(|| format!("unknown day {}", number))?;
+        let default = if args.example { day.example } else { day.input };
+        let input = source
+            .read(default)
+            .map_err(|err| format!("failed to read input: {}", err))?;
+
+        let results = day
+            .solve(&input, &args.parts)
+            .map_err(|err| format!("Day {:02}: {}", day.number, err))?;
+
+        // Text is printed as each day finishes, JSON once everything has run.
+        match args.format {
+            Format::Text => print!("{}", render(&results, Format::Text)),
+            Format::Json => all_results.extend(results),
+        }
+    }
+
+    if args.format == Format::Json {
+        print!("{}", render(&all_results, Format::Json));
+    }
+
+    Ok(())
+}
+
+fn run_verify(args: VerifyArgs) -> Result<(), String> {
+    let mut num_incorrect = 0;
+
+    for &number in &args.days {
+        let day = days::find(number).ok_or_else(|| format!("unknown day {}", number))?;
+        for check in verify(day, args.example)? {
+            let label = format!("Day {:02} part {}", day.number, check.result.part);
+            match check.outcome {
+                Outcome::Correct => println!("{}: ok", label),
+                Outcome::Unknown => println!("{}: no recorded answer", label),
+                Outcome::Incorrect { expected } => {
+                    num_incorrect += 1;
+                    println!("{}: MISMATCH", label);
+                    println!("  expected: {}", expected.trim_end());
+                    println!("  actual:   {}", check.result.answer.to_string().trim_end());
+                }
+            }
+        }
+    }
+
+    match num_incorrect {
+        0 => Ok(()),
+        _ => Err(format!("{} answer(s) did not match", num_incorrect)),
+    }
+}
+
+/// Formats a time in milliseconds, or microseconds for the fastest steps.
+fn format_time(time: Duration) -> String {
+    match time.as_secs_f64() * 1000.0 {
+        millis if millis < 1.0 => format!("{:.1}µs", millis * 1000.0),
+        millis => format!("{:.3}ms", millis),
+    }
+}
+
+/// Roughly how long to spend timing each step of a day.
+const BENCH_BUDGET: Duration = Duration::from_millis(200);
+
+fn run_bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let csv = fs::read_to_string(path)
+                .map_err(|err| format!("failed to read baseline: {}", err))?;
+            let baseline = Baseline::parse(&csv)
+                .map_err(|err| format!("{}: invalid baseline: {}", path, err))?;
+            Some(baseline)
+        }
+        None => None,
+    };
+
+    let mut all_samples = Vec::new();
+    let mut num_regressions = 0;
+
+    for &number in &args.days {
+        let day = days::find(number).ok_or_else(|| format!("unknown day {}", number))?;
+        let samples = day
+            .bench(day.input, BENCH_BUDGET)
+            .map_err(|err| format!("Day {:02}: invalid input: {}", day.number, err))?;
+
+        println!("Day {:02}: {}", day.number, day.name);
+        for sample in &samples {
+            let label = format!("{}:", sample.step);
+            let time = format_time(sample.median);
+            match baseline
+                .as_ref()
+                .and_then(|base| base.compare(sample, args.threshold))
+            {
+                Some(comparison) => {
+                    num_regressions += comparison.regression as usize;
+                    println!("  {:<7} {:>10} ({})", label, time, comparison);
+                }
+                None => println!("  {:<7} {:>10}", label, time),
+            }
+        }
+        all_samples.extend(samples);
+    }
+
+    if let Some(path) = &args.save {
+        fs::write(path, Baseline::from_samples(&all_samples).to_csv())
+            .map_err(|err| format!("failed to write baseline: {}", err))?;
+    }
+
+    match num_regressions {
+        0 => Ok(()),
+        _ => Err(format!(
+            "{} step(s) were more than {}% slower than the baseline",
+            num_regressions, args.threshold
+        )),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match parse_args(&args) {
+        Ok(Command::Run(run_args)) => run(run_args),
+        Ok(Command::Verify(verify_args)) => run_verify(verify_args),
+        Ok(Command::Bench(bench_args)) => run_bench(bench_args),
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
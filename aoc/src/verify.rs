@@ -0,0 +1,47 @@
+use aoc_common::{
+    expected::{check, Check, ExpectedAnswers},
+    Part,
+};
+
+use crate::days::Day;
+
+/// Solves both parts of `day` and checks the answers against its answers
+/// file, or against the worked example and its answers when `example` is set.
+pub fn verify(day: &Day, example: bool) -> Result<Vec<Check>, String> {
+    let (input, answers) = if example {
+        (day.example, day.example_answers)
+    } else {
+        (day.input, day.answers)
+    };
+
+    let expected = ExpectedAnswers::parse(answers)
+        .map_err(|err| format!("Day {:02}: invalid answers file: {}", day.number, err))?;
+    let results = day
+        .solve(input, &Part::BOTH)
+        .map_err(|err| format!("Day {:02}: {}", day.number, err))?;
+
+    Ok(check(&expected, results))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::expected::Outcome;
+
+    use crate::{days::DAYS, verify::verify};
+
+    #[test]
+    fn test_all_days_match_recorded_answers() {
+        for day in &DAYS {
+            for check in verify(day, false).unwrap() {
+                assert_eq!(
+                    check.outcome,
+                    Outcome::Correct,
+                    "day {} part {} answered {}",
+                    day.number,
+                    check.result.part,
+                    check.result.answer
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    runner::{solve, PartResult},
+    ParseError, Part, Solver, Span,
+};
+
+/// Known-good answers for a day, as recorded in its `answers.toml`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl ExpectedAnswers {
+    /// Parses the small subset of TOML used by the answer files: `part1` and
+    /// `part2` keys holding an integer, a basic `"string"` or a `'''`
+    /// multi-line literal string with the delimiters on their own lines.
+    /// Lines starting with `#` are comments.
+    pub fn parse(toml: &str) -> Result<Self, ParseError> {
+        let mut answers = Self::default();
+        let mut lines = Span::new(toml).lines();
+
+        while let Some(line) = lines.next() {
+            let content = line.as_str().trim_start();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once("=")?;
+            let value = value.trim();
+            let text = match value.as_str() {
+                "'''" => {
+                    let mut text = String::new();
+                    loop {
+                        let line = lines
+                            .next()
+                            .ok_or_else(|| value.end().error("a closing `'''`"))?;
+                        if line.as_str() == "'''" {
+                            break;
+                        }
+                        text.push_str(line.as_str());
+                        text.push('\n');
+                    }
+                    text
+                }
+                string if string.starts_with('"') => unescape(value)?,
+                _ => value.parse::<i64>("an integer or a string")?.to_string(),
+            };
+
+            match key.trim().as_str() {
+                "part1" => answers.part1 = Some(text),
+                "part2" => answers.part2 = Some(text),
+                _ => return Err(key.trim().error("`part1` or `part2`")),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+fn unescape(string: Span) -> Result<String, ParseError> {
+    let mut text = String::new();
+    let mut chars = string.chars().skip(1);
+
+    while let Some((char, span)) = chars.next() {
+        match char {
+            '"' => {
+                return match chars.next() {
+                    Some((_, span)) => Err(span.error("the end of the line")),
+                    None => Ok(text),
+                }
+            }
+            '\\' => match chars.next() {
+                Some(('n', _)) => text.push('\n'),
+                Some(('t', _)) => text.push('\t'),
+                Some(('"', _)) => text.push('"'),
+                Some(('\\', _)) => text.push('\\'),
+                _ => return Err(span.error("one of the escapes `\\n`, `\\t`, `\\\"` or `\\\\`")),
+            },
+            char => text.push(char),
+        }
+    }
+
+    Err(string.end().error("a closing `\"`"))
+}
+
+/// Compares an answer against the expected one, ignoring trailing whitespace
+/// on each line since editors tend to strip it from the answer files.
+pub fn answers_match(expected: &str, actual: &str) -> bool {
+    let normalize = |text: &str| -> Vec<String> {
+        text.trim_end()
+            .lines()
+            .map(|line| line.trim_end().to_owned())
+            .collect()
+    };
+    normalize(expected) == normalize(actual)
+}
+
+/// How a computed answer compares with the one recorded for the day.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Incorrect { expected: String },
+    /// The answers file has no entry for this part.
+    Unknown,
+}
+
+pub struct Check {
+    pub result: PartResult,
+    pub outcome: Outcome,
+}
+
+/// Compares each computed result with the expected answer for its part.
+pub fn check(expected: &ExpectedAnswers, results: Vec<PartResult>) -> Vec<Check> {
+    results
+        .into_iter()
+        .map(|result| {
+            let outcome = match expected.get(result.part) {
+                Some(answer) if answers_match(answer, &result.answer.to_string()) => {
+                    Outcome::Correct
+                }
+                Some(answer) => Outcome::Incorrect {
+                    expected: answer.to_owned(),
+                },
+                None => Outcome::Unknown,
+            };
+            Check { result, outcome }
+        })
+        .collect()
+}
+
+/// Solves the worked example from a day's puzzle description and panics if
+/// any part disagrees with `EXAMPLE_ANSWERS`. Each day calls this from its
+/// tests so that every day is covered without needing a personal input.
+pub fn assert_example<S: Solver>() {
+    let expected = ExpectedAnswers::parse(S::EXAMPLE_ANSWERS)
+        .unwrap_or_else(|err| panic!("invalid example answers: {}", err));
+    let results = solve::<S>(S::EXAMPLE, &Part::BOTH)
+        .unwrap_or_else(|err| panic!("day {} failed on its example: {}", S::DAY, err));
+
+    for check in check(&expected, results) {
+        if let Outcome::Incorrect { expected } = check.outcome {
+            panic!(
+                "day {} part {} answered {} for the example, expected {}",
+                S::DAY,
+                check.result.part,
+                check.result.answer,
+                expected.trim_end()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expected::{answers_match, ExpectedAnswers};
+
+    #[test]
+    fn test_parse_answers() {
+        let toml = "# Day 13\npart1 = 17\npart2 = '''\n#####\n#   #\n'''\n";
+        let answers = ExpectedAnswers::parse(toml).unwrap();
+        assert_eq!(answers.part1.as_deref(), Some("17"));
+        assert_eq!(answers.part2.as_deref(), Some("#####\n#   #\n"));
+
+        let answers = ExpectedAnswers::parse("part2 = \"a\\nb \\\"c\\\"\"").unwrap();
+        assert_eq!(answers.part1, None);
+        assert_eq!(answers.part2.as_deref(), Some("a\nb \"c\""));
+    }
+
+    #[test]
+    fn test_parse_answers_errors() {
+        let error = ExpectedAnswers::parse("part1 = 17\npart3 = 4\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = ExpectedAnswers::parse("part1 = seventeen").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+
+        assert!(ExpectedAnswers::parse("part2 = '''\n###\n").is_err());
+        assert!(ExpectedAnswers::parse("part2 = \"abc").is_err());
+    }
+
+    #[test]
+    fn test_answers_match() {
+        assert!(answers_match("1655", "1655"));
+        assert!(!answers_match("1655", "1683"));
+        assert!(answers_match("# #\n###\n", "# #  \n###   \n"));
+    }
+}
@@ -0,0 +1,352 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse::digits, ParseError, Span};
+
+/// A position within a grid as `(x, y)`, where `(0, 0)` is the top left.
+pub type Position = (usize, usize);
+
+/// A rectangular grid of cells stored in row-major order.
+///
+/// Every access is bounds-checked against both the width and the height, so
+/// a position past the end of a row can never wrap around onto the next one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from row-major cells. Panics if the cells can't be split
+    /// into rows of `width`.
+    pub fn new(cells: Vec<T>, width: usize) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells can't be split into rows of {}",
+            cells.len(),
+            width
+        );
+        let height = cells.len() / width;
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid by calling `f` with every position in row-major order.
+    /// Panics if `width` is 0.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        assert_wide(width);
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f((x, y)));
+            }
+        }
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid with every cell set to `value`. Panics if `width` is 0.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        assert_wide(width);
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Converts a position into an index into `cells`, if it is in bounds.
+    pub fn index_of(&self, (x, y): Position) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Converts an index into `cells` back into a position.
+    pub fn position_of(&self, index: usize) -> Position {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// All of the cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Every position in the grid in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Finds the position of the first cell, in row-major order, that
+    /// matches the predicate.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        let index = self.cells.iter().position(predicate)?;
+        Some(self.position_of(index))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is out of bounds", y);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The positions above, left, right and below `position` that lie within
+    /// the grid.
+    pub fn neighbours4(&self, (x, y): Position) -> impl Iterator<Item = Position> {
+        [
+            (y > 0).then(|| (x, y - 1)),
+            (x > 0).then(|| (x - 1, y)),
+            (x + 1 < self.width).then(|| (x + 1, y)),
+            (y + 1 < self.height).then(|| (x, y + 1)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// The positions of all eight cells surrounding `position`, including the
+    /// diagonals, that lie within the grid.
+    pub fn neighbours8(&self, (x, y): Position) -> impl Iterator<Item = Position> {
+        let xs = x.saturating_sub(1)..(x + 2).min(self.width);
+        let ys = y.saturating_sub(1)..(y + 2).min(self.height);
+        ys.flat_map(move |ny| xs.clone().map(move |nx| (nx, ny)))
+            .filter(move |&neighbour| neighbour != (x, y))
+    }
+
+    /// Copies the top left `width` by `height` cells into a smaller grid.
+    /// Panics if `width` is 0.
+    pub fn crop(&self, width: usize, height: usize) -> Self
+    where
+        T: Clone,
+    {
+        assert_wide(width);
+        assert!(
+            width <= self.width && height <= self.height,
+            "can't crop a {}x{} grid to {}x{}",
+            self.width,
+            self.height,
+            width,
+            height
+        );
+        let mut cells = Vec::with_capacity(width * height);
+        for row in self.rows().take(height) {
+            cells.extend_from_slice(&row[..width]);
+        }
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// Every grid has at least one column, so that rows can be split apart by
+/// width and a row can be found from an index.
+fn assert_wide(width: usize) {
+    assert!(width > 0, "a grid must be at least one cell wide");
+}
+
+impl Grid<u32> {
+    /// Parses a rectangular map of single digits, such as a height map.
+    pub fn from_digits(input: Span) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+
+        for line in input.lines() {
+            let row = digits(line)?;
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => {
+                    let message = format!("a row of {} digits", width);
+                    return Err(line.error(message));
+                }
+                _ => (),
+            }
+            cells.extend(row);
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(cells, width)),
+            _ => Err(input.error("a map of digits")),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.index_of(position) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "{:?} is outside a {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        match self.index_of(position) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "{:?} is outside a {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Renders one line per row. Any width or alignment given in the format
+/// string is applied to each cell, so `{:3}` lines numbers up in columns.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{grid::Grid, ParseError, Span};
+
+    #[test]
+    fn test_from_digits() {
+        let grid = Grid::from_digits(Span::new("123\n456")).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.cells(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let error = Grid::from_digits(Span::new("123\n4a6")).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 2,
+                column: 2,
+                expected: "a digit".to_owned(),
+                found: "`a`".to_owned(),
+            }
+        );
+
+        let error = Grid::from_digits(Span::new("123\n45")).unwrap_err();
+        assert_eq!(error.expected, "a row of 3 digits");
+    }
+
+    #[test]
+    fn test_bounds() {
+        let grid = Grid::new(vec![1, 2, 3, 4, 5, 6], 3);
+        assert_eq!(grid.get((2, 1)), Some(&6));
+        // Past the end of the first row, rather than wrapping onto the second.
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.index_of((1, 1)), Some(4));
+        assert_eq!(grid.position_of(4), (1, 1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::from_fn(3, 3, |(x, y)| x + y);
+
+        let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+
+        let edge: Vec<_> = grid.neighbours8((2, 1)).collect();
+        assert_eq!(edge, vec![(1, 0), (2, 0), (1, 1), (1, 2), (2, 2)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::new(vec![1, 2, 3, 4, 5, 6], 3);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+
+        let sums: Vec<i32> = grid.columns().map(|column| column.sum()).collect();
+        assert_eq!(sums, vec![5, 7, 9]);
+
+        assert_eq!(format!("{:3}", grid), "  1  2  3\n  4  5  6\n");
+        assert_eq!(
+            grid.map(|&cell| cell % 2 == 0).row(0),
+            &[false, true, false]
+        );
+        assert_eq!(grid.crop(2, 1), Grid::new(vec![1, 2], 2));
+
+        // Grids with no rows are allowed, unlike grids with no columns.
+        let empty = Grid::filled(3, 0, 0);
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.column(2).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one cell wide")]
+    fn test_zero_width() {
+        Grid::from_fn(0, 2, |_| 0);
+    }
+}
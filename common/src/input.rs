@@ -0,0 +1,60 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Where a day's puzzle input is loaded from.
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    /// The input that was embedded into the binary at compile time.
+    Default,
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Interprets a command line argument. No argument selects the embedded
+    /// input and `-` selects stdin, anything else is treated as a path.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Self::Default,
+            Some("-") => Self::Stdin,
+            Some(path) => Self::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, default: &str) -> io::Result<String> {
+        match self {
+            Self::Default => Ok(default.to_owned()),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Self::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::input::Source;
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(Source::from_arg(None), Source::Default);
+        assert_eq!(Source::from_arg(Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("input.txt")),
+            Source::File(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_source() {
+        assert_eq!(Source::Default.read("1,2,3").unwrap(), "1,2,3");
+    }
+}
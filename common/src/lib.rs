@@ -0,0 +1,16 @@
+//! Helpers shared between the individual Advent of Code 2021 puzzle crates.
+
+pub mod answer;
+pub mod expected;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod report;
+pub mod runner;
+pub mod solver;
+pub mod svg;
+
+pub use answer::Answer;
+pub use grid::Grid;
+pub use parse::{ParseError, Span};
+pub use solver::{Part, Solver};
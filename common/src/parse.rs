@@ -0,0 +1,300 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    iter,
+    str::FromStr,
+};
+
+/// Describes why a puzzle input could not be parsed and where in the input
+/// the problem was found. Lines and columns both start from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A slice of the puzzle input that remembers where it started, so that
+/// anything parsed out of it can report the location of a problem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Returns the sub-span between two byte offsets, working out its line
+    /// and column from the text that was skipped over.
+    fn slice(&self, start: usize, end: usize) -> Self {
+        let skipped = &self.text[..start];
+        let (line, column) = match skipped.rfind('\n') {
+            Some(newline) => (
+                self.line + skipped.matches('\n').count(),
+                skipped[newline + 1..].chars().count() + 1,
+            ),
+            None => (self.line, self.column + skipped.chars().count()),
+        };
+
+        Self {
+            text: &self.text[start..end],
+            line,
+            column,
+        }
+    }
+
+    /// Returns the sub-span for a `&str` that was taken from this span.
+    fn sub(&self, part: &'a str) -> Self {
+        let start = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.slice(start, start + part.len())
+    }
+
+    /// Splits the span in two at a byte offset, which must lie on a character
+    /// boundary.
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        (self.slice(0, mid), self.slice(mid, self.text.len()))
+    }
+
+    /// An empty span positioned just after the end of this one.
+    pub fn end(&self) -> Self {
+        self.slice(self.text.len(), self.text.len())
+    }
+
+    /// Builds an error pointing at this span.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = match self.text.lines().next() {
+            Some(text) if !text.is_empty() => format!("`{}`", text),
+            _ => "end of line".to_owned(),
+        };
+
+        ParseError {
+            line: self.line,
+            column: self.column,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// Parses the whole span, describing it as `expected` if that fails.
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    /// Splits the span into lines, accepting both `\n` and `\r\n` line
+    /// endings. Trailing whitespace is removed from every line and blank lines
+    /// at the very end of the input are skipped.
+    pub fn lines(self) -> impl Iterator<Item = Span<'a>> {
+        let mut rest = Some(self.trim_end());
+        iter::from_fn(move || {
+            let span = rest.take().filter(|span| !span.is_empty())?;
+            match span.text.find('\n') {
+                Some(newline) => {
+                    rest = Some(span.slice(newline + 1, span.text.len()));
+                    Some(span.slice(0, newline).trim_end())
+                }
+                None => Some(span),
+            }
+        })
+    }
+
+    /// Splits the span into blocks of lines that are separated by one or more
+    /// blank lines, with the same line ending handling as `lines`.
+    pub fn sections(self) -> impl Iterator<Item = Span<'a>> {
+        let mut lines = self.lines().peekable();
+        iter::from_fn(move || {
+            while lines.next_if(Span::is_empty).is_some() {}
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.is_empty()) {
+                last = line;
+            }
+
+            let start = first.text.as_ptr() as usize - self.text.as_ptr() as usize;
+            let end = last.text.as_ptr() as usize - self.text.as_ptr() as usize + last.text.len();
+            Some(Span {
+                text: &self.text[start..end],
+                line: first.line,
+                column: first.column,
+            })
+        })
+    }
+
+    pub fn split(self, delimiter: &'a str) -> impl Iterator<Item = Span<'a>> {
+        let mut rest = Some(self);
+        iter::from_fn(move || {
+            let span = rest.take()?;
+            match span.text.find(delimiter) {
+                Some(start) => {
+                    rest = Some(span.slice(start + delimiter.len(), span.text.len()));
+                    Some(span.slice(0, start))
+                }
+                None => Some(span),
+            }
+        })
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.split_whitespace().map(move |part| self.sub(part))
+    }
+
+    /// Splits the span around the first occurrence of `delimiter`, failing
+    /// if the delimiter is missing.
+    pub fn split_once(&self, delimiter: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.find(delimiter) {
+            Some(start) => Ok((
+                self.slice(0, start),
+                self.slice(start + delimiter.len(), self.text.len()),
+            )),
+            None => Err(self.end().error(format!("`{}`", delimiter))),
+        }
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("`{}`", prefix))),
+        }
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    pub fn trim_end(&self) -> Self {
+        self.sub(self.text.trim_end())
+    }
+
+    /// Iterates over each character along with a span covering just it.
+    pub fn chars(self) -> impl Iterator<Item = (char, Span<'a>)> {
+        let mut line = self.line;
+        let mut column = self.column;
+        self.text.char_indices().map(move |(i, char)| {
+            let span = Span {
+                text: &self.text[i..i + char.len_utf8()],
+                line,
+                column,
+            };
+            if char == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+            (char, span)
+        })
+    }
+}
+
+/// Parses a single line of comma separated values, e.g. `3,4,3,1,2`.
+pub fn comma_separated<T: FromStr>(span: Span, expected: &str) -> Result<Vec<T>, ParseError> {
+    span.split(",").map(|value| value.parse(expected)).collect()
+}
+
+/// Converts a line of decimal digits into their numeric values.
+pub fn digits(line: Span) -> Result<Vec<u32>, ParseError> {
+    line.chars()
+        .map(|(char, span)| char.to_digit(10).ok_or_else(|| span.error("a digit")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{comma_separated, Span};
+
+    #[test]
+    fn test_comma_separated() {
+        let values: Vec<u32> = comma_separated(Span::new("3,4,3,1,2"), "a number").unwrap();
+        assert_eq!(values, vec![3, 4, 3, 1, 2]);
+
+        let error = comma_separated::<u32>(Span::new("3,4,x,1"), "a number").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn test_span_positions() {
+        let input = Span::new("ab\ncd -> ef\n");
+        let lines: Vec<_> = input.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let (left, right) = lines[1].split_once(" -> ").unwrap();
+        assert_eq!((left.as_str(), left.line(), left.column()), ("cd", 2, 1));
+        assert_eq!((right.as_str(), right.line(), right.column()), ("ef", 2, 7));
+
+        let (first, rest) = lines[1].split_at(1);
+        assert_eq!((first.as_str(), rest.as_str(), rest.column()), ("c", "d -> ef", 2));
+
+        let error = lines[0].split_once(" -> ").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.to_string(), "line 1, column 3: expected ` -> `, found end of line");
+    }
+
+    #[test]
+    fn test_line_endings() {
+        let expected = vec!["199", "200", "", "  208"];
+        for input in [
+            "199\n200\n\n  208",
+            "199\n200\n\n  208\n",
+            "199\r\n200\r\n\r\n  208\r\n",
+            "199 \n200\t\n \n  208\n\n\n",
+        ] {
+            let lines: Vec<_> = Span::new(input).lines().map(|line| line.as_str()).collect();
+            assert_eq!(lines, expected);
+        }
+    }
+
+    #[test]
+    fn test_sections() {
+        for input in [
+            "NNCB\n\nCH -> B\nHH -> N\n",
+            "NNCB\r\n\r\nCH -> B\r\nHH -> N\r\n",
+            "\nNNCB  \n\n\n\nCH -> B\nHH -> N\n\n",
+        ] {
+            let sections: Vec<_> = Span::new(input).sections().collect();
+            assert_eq!(sections.len(), 2);
+            assert_eq!(sections[0].as_str(), "NNCB");
+
+            let rules: Vec<_> = sections[1].lines().map(|line| line.as_str()).collect();
+            assert_eq!(rules, vec!["CH -> B", "HH -> N"]);
+        }
+
+        let sections: Vec<_> = Span::new("a\n\n\nb\nc").sections().collect();
+        assert_eq!((sections[1].line(), sections[1].column()), (4, 1));
+    }
+}
@@ -0,0 +1,132 @@
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use crate::{answer::json_string, runner::PartResult};
+
+/// How the answers are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("unknown format `{}`, expected `text` or `json`", format)),
+        }
+    }
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn render(results: &[PartResult], format: Format) -> String {
+    match format {
+        Format::Text => render_text(results),
+        Format::Json => render_json(results),
+    }
+}
+
+/// Renders the results as human readable text, with a heading before each
+/// day. Multi-line answers start on the line below their part.
+fn render_text(results: &[PartResult]) -> String {
+    let mut text = String::new();
+    let mut current_day = None;
+
+    for result in results {
+        if current_day != Some(result.day) {
+            current_day = Some(result.day);
+            writeln!(text, "Day {:02}: {}", result.day, result.name).unwrap();
+        }
+
+        let elapsed = milliseconds(result.elapsed);
+        if result.answer.is_multiline() {
+            writeln!(text, "  Part {}: ({:.3}ms)", result.part, elapsed).unwrap();
+            writeln!(text, "{}", result.answer.to_string().trim_end()).unwrap();
+        } else {
+            writeln!(text, "  Part {}: {} ({:.3}ms)", result.part, result.answer, elapsed).unwrap();
+        }
+    }
+
+    text
+}
+
+/// Renders the results as a JSON array with one object per part.
+fn render_json(results: &[PartResult]) -> String {
+    let objects: Vec<String> = results
+        .iter()
+        .map(|result| {
+            format!(
+                "  {{\"day\": {}, \"name\": {}, \"part\": {}, \"answer\": {}, \"time_ms\": {:.3}}}",
+                result.day,
+                json_string(result.name),
+                result.part,
+                result.answer.to_json(),
+                milliseconds(result.elapsed),
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        "[]\n".to_owned()
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        report::{render, Format},
+        runner::PartResult,
+        Answer, Part,
+    };
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                day: 13,
+                name: "Transparent Origami",
+                part: Part::One,
+                answer: Answer::from(17),
+                elapsed: Duration::from_micros(1500),
+            },
+            PartResult {
+                day: 13,
+                name: "Transparent Origami",
+                part: Part::Two,
+                answer: Answer::from("##\n#.\n".to_owned()),
+                elapsed: Duration::from_micros(250),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render_text() {
+        assert_eq!(
+            render(&results(), Format::Text),
+            "Day 13: Transparent Origami\n  Part 1: 17 (1.500ms)\n  Part 2: (0.250ms)\n##\n#.\n"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        assert_eq!(
+            render(&results(), Format::Json),
+            concat!(
+                "[\n",
+                "  {\"day\": 13, \"name\": \"Transparent Origami\", \"part\": 1, \"answer\": 17, \"time_ms\": 1.500},\n",
+                "  {\"day\": 13, \"name\": \"Transparent Origami\", \"part\": 2, \"answer\": \"##\\n#.\\n\", \"time_ms\": 0.250}\n",
+                "]\n",
+            )
+        );
+        assert_eq!(render(&[], Format::Json), "[]\n");
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    process,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    input::Source,
+    report::{render, Format},
+    solver::PartOutput,
+    Answer, ParseError, Part, Solver,
+};
+
+/// The answer to one part of a puzzle along with how long it took to compute.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub day: u8,
+    pub name: &'static str,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Why a day couldn't produce its answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The input was parsed, but one of the parts failed on it.
+    Part {
+        part: Part,
+        message: String,
+    },
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "invalid input: {}", err),
+            Self::Part { message, .. } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// Parses `input` and runs the requested parts of a day against it, stopping
+/// at the first part that fails.
+pub fn solve<S: Solver>(input: &str, parts: &[Part]) -> Result<Vec<PartResult>, SolveError> {
+    let data = S::parse(input)?;
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&data).into_answer(),
+                Part::Two => S::part2(&data).into_answer(),
+            };
+            let elapsed = start.elapsed();
+
+            Ok(PartResult {
+                day: S::DAY,
+                name: S::NAME,
+                part,
+                answer: answer.map_err(|message| SolveError::Part { part, message })?,
+                elapsed,
+            })
+        })
+        .collect()
+}
+
+/// Reports an error that prevents a binary from continuing and exits.
+pub fn exit_with_error(err: impl Display) -> ! {
+    eprintln!("{}", err);
+    process::exit(1);
+}
+
+/// Entry point shared by the day binaries.
+///
+/// Accepts `[--format text|json] [--example | PATH]`, where the path may be
+/// `-` to read the input from stdin. Without a path the embedded input is
+/// used, or the puzzle's worked example with `--example`.
+pub fn run_day<S: Solver>() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut format = Format::Text;
+    let mut example = false;
+    let mut path = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" => {
+                let value = iter
+                    .next()
+                    .unwrap_or_else(|| exit_with_error("--format requires a value"));
+                format = value.parse().unwrap_or_else(|err| exit_with_error(err));
+            }
+            "--example" => example = true,
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => exit_with_error(format!("unexpected argument `{}`", arg)),
+        }
+    }
+
+    if example && path.is_some() {
+        exit_with_error("--example cannot be used with an input path");
+    }
+
+    let default = if example { S::EXAMPLE } else { S::INPUT };
+    let input = Source::from_arg(path)
+        .read(default)
+        .unwrap_or_else(|err| exit_with_error(format!("Failed to read input: {}", err)));
+    let results = solve::<S>(&input, &Part::BOTH)
+        .unwrap_or_else(|err| exit_with_error(format!("Day {:02}: {}", S::DAY, err)));
+    print!("{}", render(&results, format));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        runner::{solve, SolveError},
+        Answer, ParseError, Part, Solver, Span,
+    };
+
+    /// Halves a number, failing on odd numbers in part 2.
+    struct Halve;
+
+    impl Solver for Halve {
+        const DAY: u8 = 99;
+        const NAME: &'static str = "Halve";
+        const INPUT: &'static str = "";
+        const ANSWERS: &'static str = "";
+        const EXAMPLE: &'static str = "";
+        const EXAMPLE_ANSWERS: &'static str = "";
+
+        type Data = u32;
+        type Part1 = u32;
+        type Part2 = Result<u32, String>;
+
+        fn parse(input: &str) -> Result<Self::Data, ParseError> {
+            Span::new(input).trim_end().parse("a number")
+        }
+
+        fn part1(data: &Self::Data) -> Self::Part1 {
+            data / 2
+        }
+
+        fn part2(data: &Self::Data) -> Self::Part2 {
+            match data % 2 {
+                0 => Ok(data / 2),
+                _ => Err(format!("{} is odd", data)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let results = solve::<Halve>("10\n", &Part::BOTH).unwrap();
+        let answers: Vec<_> = results.into_iter().map(|result| result.answer).collect();
+        assert_eq!(answers, vec![Answer::Unsigned(5), Answer::Unsigned(5)]);
+
+        assert_eq!(solve::<Halve>("7\n", &[Part::One]).unwrap().len(), 1);
+        let error = solve::<Halve>("7\n", &Part::BOTH).unwrap_err();
+        assert_eq!(
+            error,
+            SolveError::Part {
+                part: Part::Two,
+                message: "7 is odd".to_owned()
+            }
+        );
+        assert_eq!(error.to_string(), "7 is odd");
+
+        let error = solve::<Halve>("x\n", &Part::BOTH).unwrap_err();
+        assert!(matches!(error, SolveError::Parse(_)));
+        assert!(error
+            .to_string()
+            .starts_with("invalid input: line 1, column 1"));
+    }
+}
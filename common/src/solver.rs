@@ -0,0 +1,79 @@
+use std::fmt::{self, Display};
+
+use crate::{answer::Answer, parse::ParseError};
+
+/// One of the two halves of a day's puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The common interface implemented by every day so that it can be run by
+/// the `aoc` dispatcher.
+///
+/// Parsing is kept separate from the two parts so the input only has to be
+/// processed once, and so each step can be timed on its own.
+pub trait Solver {
+    const DAY: u8;
+    const NAME: &'static str;
+    /// The puzzle input embedded into the crate, used when no other input is
+    /// supplied.
+    const INPUT: &'static str;
+    /// The known-good answers for `INPUT`, in the format read by
+    /// `ExpectedAnswers::parse`.
+    const ANSWERS: &'static str;
+    /// The worked example from the puzzle description.
+    const EXAMPLE: &'static str;
+    /// The answers given in the puzzle description for `EXAMPLE`, in the
+    /// same format as `ANSWERS`. Parts without a worked answer are left out.
+    const EXAMPLE_ANSWERS: &'static str;
+
+    type Data;
+    type Part1: PartOutput;
+    type Part2: PartOutput;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError>;
+    fn part1(data: &Self::Data) -> Self::Part1;
+    fn part2(data: &Self::Data) -> Self::Part2;
+}
+
+/// Something a part can return. Anything that converts into an `Answer` can be
+/// returned as it is, and a part that can fail returns a `Result` so that its
+/// error is reported in place of an answer.
+pub trait PartOutput {
+    fn into_answer(self) -> Result<Answer, String>;
+}
+
+impl<T: Into<Answer>> PartOutput for T {
+    fn into_answer(self) -> Result<Answer, String> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>, E: Display> PartOutput for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, String> {
+        self.map(Into::into).map_err(|err| err.to_string())
+    }
+}
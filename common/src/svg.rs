@@ -0,0 +1,137 @@
+use std::fmt::Write;
+
+/// The space left around the plot area, which holds the legend.
+pub const MARGIN: f64 = 40.0;
+
+/// How far apart the labels in the legend are placed.
+const LEGEND_SPACING: f64 = 110.0;
+
+/// Builds a chart as an SVG document with a white background.
+///
+/// Points are given relative to the plot area, which is the chart with a
+/// margin of `MARGIN` taken off every side. The legend runs along the top
+/// margin, with a label added for each call to `legend`.
+pub struct Svg {
+    text: String,
+    width: u32,
+    height: u32,
+    num_labels: usize,
+}
+
+impl Svg {
+    pub fn new(width: u32, height: u32) -> Self {
+        let mut text = String::new();
+        writeln!(
+            text,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            width, height
+        )
+        .unwrap();
+        writeln!(
+            text,
+            r#"  <rect width="{}" height="{}" fill="white"/>"#,
+            width, height
+        )
+        .unwrap();
+
+        Self {
+            text,
+            width,
+            height,
+            num_labels: 0,
+        }
+    }
+
+    pub fn plot_width(&self) -> f64 {
+        self.width as f64 - 2.0 * MARGIN
+    }
+
+    pub fn plot_height(&self) -> f64 {
+        self.height as f64 - 2.0 * MARGIN
+    }
+
+    /// Draws a line through `points`. Nothing is drawn if there are no
+    /// points.
+    pub fn polyline(
+        &mut self,
+        points: impl IntoIterator<Item = (f64, f64)>,
+        colour: &str,
+        stroke_width: f64,
+    ) {
+        let points: Vec<_> = points
+            .into_iter()
+            .map(|(x, y)| format!("{:.1},{:.1}", MARGIN + x, MARGIN + y))
+            .collect();
+        if points.is_empty() {
+            return;
+        }
+        writeln!(
+            self.text,
+            r#"  <polyline fill="none" stroke="{}" stroke-width="{}" points="{}"/>"#,
+            colour,
+            stroke_width,
+            points.join(" ")
+        )
+        .unwrap();
+    }
+
+    /// Adds a label to the end of the legend, written in `colour`.
+    pub fn legend(&mut self, label: &str, colour: &str) {
+        writeln!(
+            self.text,
+            r#"  <text x="{}" y="{}" font-family="sans-serif" font-size="12" fill="{}">{}</text>"#,
+            MARGIN + self.num_labels as f64 * LEGEND_SPACING,
+            MARGIN / 2.0,
+            colour,
+            escape(label)
+        )
+        .unwrap();
+        self.num_labels += 1;
+    }
+
+    /// Closes the document and returns its text.
+    pub fn finish(mut self) -> String {
+        self.text.push_str("</svg>\n");
+        self.text
+    }
+}
+
+/// Replaces the characters that have a special meaning in XML text and
+/// attributes with entities.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::svg::Svg;
+
+    #[test]
+    fn test_svg() {
+        let mut svg = Svg::new(200, 100);
+        assert_eq!((svg.plot_width(), svg.plot_height()), (120.0, 20.0));
+        svg.polyline([(0.0, 0.0), (120.0, 20.0)], "red", 1.5);
+        svg.polyline([], "blue", 1.0);
+        svg.legend("first", "red");
+        svg.legend("second", "blue");
+        svg.legend("<b> & \"c\"", "green");
+
+        let text = svg.finish();
+        assert!(text.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"200\""));
+        assert!(text.contains(r#"stroke-width="1.5" points="40.0,40.0 160.0,60.0"/>"#));
+        assert_eq!(text.matches("<polyline").count(), 1);
+        assert!(text.contains(r#"<text x="150" y="20""#));
+        assert!(text.contains(">&lt;b&gt; &amp; &quot;c&quot;</text>"));
+        assert!(text.ends_with("</svg>\n"));
+    }
+}